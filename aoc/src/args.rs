@@ -0,0 +1,409 @@
+use crate::scaffold;
+use libaoc::log::{self, Filter, Level};
+use libaoc::Format;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Help,
+    List,
+    Run { day: u8, part: Option<u8>, options: Options },
+    All { options: Options },
+    New { day: u8, lib2d: bool },
+    Check { day: Option<u8>, input: Option<String> },
+    Fetch { day: Option<u8> },
+    Submit { day: u8, part: u8, input: Option<String> },
+    Watch { day: u8 },
+    Generate { day: u8, size: Option<usize>, seed: u64, output: Option<String> },
+    Keygen,
+    Encrypt { day: Option<u8> },
+    Decrypt { day: Option<u8> },
+}
+
+/// Flags shared by `run` and `all`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Options {
+    pub input: Option<String>,
+    pub accept: bool,
+    /// Number of timed iterations, when benchmarking.
+    pub bench: Option<usize>,
+    pub format: Format,
+    /// Solve the days concurrently and summarize them in a table.
+    pub parallel: bool,
+    /// Count allocations per phase instead of printing answers.
+    pub alloc: bool,
+    /// When benchmarking, flag phases more than this many percent slower than their best on record.
+    pub compare: Option<u32>,
+}
+
+pub const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  list                                   list the available days
+  run --day N [--part P] [--input PATH]  run one day (both parts unless --part is given)
+  all [--input NAME]                     run every day in sequence
+  check [--day N] [--input PATH]         report every problem with a day's input (or each day's
+                                         input NAME) without solving it
+  new dNN [--lib2d]                      create a crate for a new day and add it to the workspace
+  fetch [--day N]                        download a day's input (or every day's) unless it's already saved
+  submit --day N --part P [--input PATH]
+                                         solve one part and send the answer to the site
+  watch --day N                          rebuild and re-run a day on its example and input whenever
+                                         its sources or those files change, showing what changed
+  generate --day N [--size S] [--seed X] [--output PATH]
+                                         write a random valid input for a day (to stdout by default)
+  keygen                                 create a key for encrypting inputs
+  encrypt [--day N]                      encrypt a day's input (or every day's) in place
+  decrypt [--day N]                      decrypt a day's input (or every day's) in place
+  help                                   show this message
+
+Options:
+  --accept           record the answers as accepted in the ledger
+  --bench            time parse, part1 and part2 separately instead of printing answers
+  --iterations N     how many timed runs --bench takes per phase (default 10)
+  --compare          benchmark, and flag phases slower than their best recorded run (implies --bench)
+  --threshold PCT    how much slower --compare tolerates, in percent (default 10)
+  --json             print one JSON object per answer (or per benchmarked phase)
+  --parallel         (all only) solve every day at once, then print a summary table
+  --alloc            count allocations, bytes allocated and peak live bytes for parse, part1 and
+                     part2 (needs a build with `--features alloc-stats`)
+  --size S           (generate only) how big an input to make; what S counts depends on the day
+  --seed X           (generate only) the same seed always makes the same input (default 1)
+
+Logging (to stderr) is controlled by -v (debug), -vv (trace) or --log FILTER, falling back to
+$AOC_LOG. A filter is a comma-separated list of levels, optionally per day: `d08=trace,info`.
+
+Every answer is compared against the ledger's accepted answer for the same input, and a
+mismatch makes the command fail. The ledger is `answers.ledger` in the workspace root, or $AOC_LEDGER.
+
+`fetch` and `submit` log in with the session cookie in $AOC_SESSION and downloads from $AOC_URL (default
+https://adventofcode.com) for the event in $AOC_YEAR (default 2025). Set $AOC_CONTACT to an email or
+repository URL to include it in the User-Agent. `submit` records what the site says in the ledger,
+won't send an answer it already knows is wrong (or beyond one that was too high or too low), and
+waits out any cooldown the site asks for.
+
+Every --bench run is appended to `bench.history` in the workspace root (or $AOC_BENCH_HISTORY),
+keyed by git revision, which is what --compare measures against. Only runs from the same kind of
+build (debug or release, with or without alloc-stats) are compared.
+
+Puzzle inputs shouldn't be shared, so they can be kept encrypted: `aoc keygen` writes a key to
+`.aoc-key` in the workspace root (or $AOC_KEY_FILE), which git ignores, and `aoc encrypt` then
+encrypts every input with it. Encrypted inputs are decrypted transparently wherever they're read,
+using $AOC_KEY (64 hex digits) if it's set, otherwise the key file. `fetch` encrypts what it
+downloads whenever a key is available.
+
+Inputs are resolved from --input, then $AOC_INPUT, then the day's own `input` file.
+Relative paths are also looked for in the day's directory, and `-` reads stdin.
+`all` ignores $AOC_INPUT and reads NAME (default `input`) from each day's directory.";
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+pub const DEFAULT_SEED: u64 = 1;
+pub const DEFAULT_THRESHOLD: u32 = 10;
+
+/// Parses the command line. Logging flags are applied as they are found, since they are global.
+pub fn parse<I>(args: I) -> Result<Command, String>
+where I: IntoIterator<Item = String> {
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        None => return Ok(Command::Help),
+        Some(command) => command,
+    };
+
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut accept = false;
+    let mut bench = false;
+    let mut iterations = None;
+    let mut format = Format::Text;
+    let mut name = None;
+    let mut lib2d = false;
+    let mut parallel = false;
+    let mut alloc = false;
+    let mut compare = false;
+    let mut threshold = None;
+    let mut size = None;
+    let mut seed = None;
+    let mut output = None;
+    while let Some(flag) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {flag}"));
+        match flag.as_str() {
+            "--day" | "-d" => day = Some(parse_num(&value()?, "day")?),
+            "--part" | "-p" => part = Some(parse_num(&value()?, "part")?),
+            "--input" | "-i" => input = Some(value()?),
+            "--accept" => accept = true,
+            "--bench" => bench = true,
+            "--iterations" | "-n" => {
+                let value = value()?;
+                let count = value.parse().ok().filter(|&n| n > 0);
+                iterations = Some(count.ok_or_else(|| format!("Invalid iterations {value:?}"))?);
+            }
+            "--json" => format = Format::Json,
+            "--lib2d" => lib2d = true,
+            "--parallel" => parallel = true,
+            "--alloc" => alloc = true,
+            "--compare" => compare = true,
+            "--threshold" => {
+                let value = value()?;
+                let percent = value.trim_end_matches('%').parse().ok();
+                threshold = Some(percent.ok_or_else(|| format!("Invalid threshold {value:?}"))?);
+            }
+            "--size" => {
+                let value = value()?;
+                size = Some(value.parse().map_err(|_| format!("Invalid size {value:?}"))?);
+            }
+            "--seed" => {
+                let value = value()?;
+                seed = Some(value.parse().map_err(|_| format!("Invalid seed {value:?}"))?);
+            }
+            "--output" | "-o" => output = Some(value()?),
+            "-v" => log::init(Filter::level(Level::Debug)),
+            "-vv" => log::init(Filter::level(Level::Trace)),
+            "--log" => log::init(Filter::parse(&value()?)?),
+            _ if !flag.starts_with('-') && name.is_none() && command == "new" => name = Some(flag),
+            _ => return Err(format!("Unrecognized argument {flag}")),
+        }
+    }
+
+    let allowed: &[&str] = match command.as_str() {
+        "help" | "--help" | "-h" | "list" | "keygen" => &[],
+        "run" => &[
+            "--day", "--part", "--input", "--accept", "--bench", "--iterations", "--json", "--alloc", "--compare",
+            "--threshold",
+        ],
+        "all" => &[
+            "--input", "--accept", "--bench", "--iterations", "--json", "--parallel", "--alloc", "--compare",
+            "--threshold",
+        ],
+        "new" => &["--lib2d"],
+        "check" => &["--day", "--input"],
+        "fetch" | "watch" | "encrypt" | "decrypt" => &["--day"],
+        "submit" => &["--day", "--part", "--input"],
+        "generate" => &["--day", "--size", "--seed", "--output"],
+        _ => return Err(format!("Unrecognized command {command}")),
+    };
+    let given = [
+        ("--day", day.is_some()),
+        ("--part", part.is_some()),
+        ("--input", input.is_some()),
+        ("--accept", accept),
+        ("--bench", bench),
+        ("--iterations", iterations.is_some()),
+        ("--json", format == Format::Json),
+        ("--lib2d", lib2d),
+        ("--parallel", parallel),
+        ("--alloc", alloc),
+        ("--compare", compare),
+        ("--threshold", threshold.is_some()),
+        ("--size", size.is_some()),
+        ("--seed", seed.is_some()),
+        ("--output", output.is_some()),
+    ];
+    if let Some((flag, _)) = given.iter().find(|(flag, set)| *set && !allowed.contains(flag)) {
+        return Err(format!("{flag} doesn't apply to {command}"));
+    }
+
+    let bench = bench || compare;
+    if iterations.is_some() && !bench {
+        return Err("--iterations only applies to --bench".to_string());
+    }
+    if threshold.is_some() && !compare {
+        return Err("--threshold only applies to --compare".to_string());
+    }
+    let bench = bench.then(|| iterations.unwrap_or(DEFAULT_ITERATIONS));
+    let compare = compare.then(|| threshold.unwrap_or(DEFAULT_THRESHOLD));
+    if parallel && bench.is_some() {
+        return Err("--parallel can't be combined with --bench".to_string());
+    }
+    if alloc && (bench.is_some() || parallel) {
+        return Err("--alloc can't be combined with --bench or --parallel".to_string());
+    }
+    if accept && (bench.is_some() || alloc) {
+        return Err("--accept can't be combined with --bench or --alloc, which don't check answers".to_string());
+    }
+    let options = Options { input, accept, bench, format, parallel, alloc, compare };
+
+    match command.as_str() {
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        "list" => Ok(Command::List),
+        "all" => Ok(Command::All { options }),
+        "new" => {
+            let name = name.ok_or("new requires the day to create, e.g. `aoc new d09`")?;
+            Ok(Command::New { day: scaffold::parse_day(&name)?, lib2d })
+        }
+        "check" => Ok(Command::Check { day, input: options.input }),
+        "fetch" => Ok(Command::Fetch { day }),
+        "keygen" => Ok(Command::Keygen),
+        "encrypt" => Ok(Command::Encrypt { day }),
+        "decrypt" => Ok(Command::Decrypt { day }),
+        "watch" => Ok(Command::Watch { day: day.ok_or("watch requires --day")? }),
+        "submit" => {
+            let day = day.ok_or("submit requires --day")?;
+            match part {
+                Some(part @ (1 | 2)) => Ok(Command::Submit { day, part, input: options.input }),
+                Some(part) => Err(format!("There is no part {part}")),
+                None => Err("submit requires --part".to_string()),
+            }
+        }
+        "generate" => {
+            let day = day.ok_or("generate requires --day")?;
+            Ok(Command::Generate { day, size, seed: seed.unwrap_or(DEFAULT_SEED), output })
+        }
+        "run" => {
+            let day = day.ok_or("run requires --day")?;
+            if let Some(part) = part {
+                if part != 1 && part != 2 {
+                    return Err(format!("There is no part {part}"));
+                }
+            }
+            Ok(Command::Run { day, part, options })
+        }
+        _ => unreachable!("unrecognized commands are rejected above"),
+    }
+}
+
+fn parse_num(value: &str, name: &str) -> Result<u8, String> {
+    value.parse().map_err(|_| format!("Invalid {name} {value:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    fn options(line: &str) -> Options {
+        match parse(args(line)).unwrap() {
+            Command::All { options } => options,
+            other => panic!("Expected all, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn run() {
+        let command = parse(args("run --day 5 --part 2 --input d05/example --accept --json")).unwrap();
+        let options = Options {
+            input: Some("d05/example".to_string()),
+            accept: true,
+            bench: None,
+            format: Format::Json,
+            parallel: false,
+            alloc: false,
+            compare: None,
+        };
+        assert_eq!(command, Command::Run { day: 5, part: Some(2), options });
+    }
+
+    #[test]
+    fn run_needs_day() {
+        assert!(parse(args("run --part 1")).is_err());
+        assert!(parse(args("run --day 1 --part 3")).is_err());
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(options("all --bench").bench, Some(DEFAULT_ITERATIONS));
+        assert_eq!(options("all --bench -n 3").bench, Some(3));
+        assert!(parse(args("all --iterations 3")).is_err());
+        assert!(parse(args("all --bench --iterations 0")).is_err());
+    }
+
+    #[test]
+    fn compare() {
+        let compared = options("all --compare");
+        assert_eq!((compared.bench, compared.compare), (Some(DEFAULT_ITERATIONS), Some(DEFAULT_THRESHOLD)));
+        assert_eq!(options("all --compare --threshold 25% -n 3").compare, Some(25));
+        assert_eq!(options("all --bench --compare --threshold 25 -n 3").bench, Some(3));
+        assert!(parse(args("all --bench --threshold 25")).is_err());
+    }
+
+    #[test]
+    fn alloc() {
+        assert!(options("all --alloc").alloc);
+        assert!(parse(args("all --alloc --bench")).is_err());
+        assert!(parse(args("all --alloc --parallel")).is_err());
+    }
+
+    #[test]
+    fn flags_must_apply() {
+        assert_eq!(parse(args("all --part 1")).unwrap_err(), "--part doesn't apply to all");
+        assert_eq!(parse(args("fetch --part 1")).unwrap_err(), "--part doesn't apply to fetch");
+        assert!(parse(args("check -d 1 --json")).is_err());
+        assert!(parse(args("keygen --day 1")).is_err());
+        assert!(parse(args("all --accept --bench")).is_err());
+        assert!(parse(args("run -d 1 --accept --compare")).is_err());
+        assert!(parse(args("run -d 1 --accept --alloc")).is_err());
+        assert!(parse(args("bogus --day 1")).unwrap_err().contains("Unrecognized command"));
+    }
+
+    #[test]
+    fn parallel() {
+        assert!(options("all --parallel").parallel);
+        assert!(parse(args("run --day 1 --parallel")).is_err());
+        assert!(parse(args("all --parallel --bench")).is_err());
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(parse(args("new d09 --lib2d")).unwrap(), Command::New { day: 9, lib2d: true });
+        assert!(parse(args("new")).is_err());
+        assert!(parse(args("run --day 1 extra")).is_err());
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(parse(args("check")).unwrap(), Command::Check { day: None, input: None });
+        assert_eq!(
+            parse(args("check -d 5 -i big")).unwrap(),
+            Command::Check { day: Some(5), input: Some("big".to_string()) }
+        );
+    }
+
+    #[test]
+    fn fetch() {
+        assert_eq!(parse(args("fetch")).unwrap(), Command::Fetch { day: None });
+        assert_eq!(parse(args("fetch --day 3")).unwrap(), Command::Fetch { day: Some(3) });
+    }
+
+    #[test]
+    fn submit() {
+        assert_eq!(parse(args("submit -d 5 -p 2")).unwrap(), Command::Submit { day: 5, part: 2, input: None });
+        assert!(parse(args("submit -d 5")).is_err());
+        assert!(parse(args("submit -p 1")).is_err());
+        assert!(parse(args("submit -d 5 -p 3")).is_err());
+    }
+
+    #[test]
+    fn watch() {
+        assert_eq!(parse(args("watch -d 8")).unwrap(), Command::Watch { day: 8 });
+        assert!(parse(args("watch")).is_err());
+    }
+
+    #[test]
+    fn generate() {
+        assert_eq!(
+            parse(args("generate -d 4 --size 20 --seed 7 -o big")).unwrap(),
+            Command::Generate { day: 4, size: Some(20), seed: 7, output: Some("big".to_string()) }
+        );
+        assert_eq!(
+            parse(args("generate --day 4")).unwrap(),
+            Command::Generate { day: 4, size: None, seed: DEFAULT_SEED, output: None }
+        );
+        assert!(parse(args("generate --size 20")).is_err());
+        assert!(parse(args("run --day 4 --seed 7")).is_err());
+    }
+
+    #[test]
+    fn crypt() {
+        assert_eq!(parse(args("keygen")).unwrap(), Command::Keygen);
+        assert_eq!(parse(args("encrypt")).unwrap(), Command::Encrypt { day: None });
+        assert_eq!(parse(args("decrypt -d 2")).unwrap(), Command::Decrypt { day: Some(2) });
+    }
+
+    #[test]
+    fn no_args() {
+        assert_eq!(parse(args("")).unwrap(), Command::Help);
+    }
+}
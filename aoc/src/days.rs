@@ -0,0 +1,51 @@
+use libaoc::alloc::{measure, AllocPhases};
+use libaoc::bench::{bench, PhaseStats};
+use libaoc::parse::ParseError;
+use libaoc::{check, generate, solve, Solution, Solved};
+
+pub struct Day {
+    pub day: u8,
+    pub dir: &'static str,
+    pub solve: fn(&str, &[u8]) -> Result<Solved, ParseError>,
+    pub bench: fn(&str, usize) -> Result<PhaseStats, ParseError>,
+    pub alloc: fn(&str) -> Result<AllocPhases, ParseError>,
+    /// Every problem with an input's format.
+    pub check: fn(&str) -> Vec<ParseError>,
+    /// Makes a synthetic input from a seed and an optional size.
+    pub generate: fn(u64, Option<usize>) -> String,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("d{:02}", self.day)
+    }
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            day: <$solution>::DAY,
+            dir: <$solution>::DIR,
+            solve: solve::<$solution>,
+            bench: bench::<$solution>,
+            alloc: measure::<$solution>,
+            check: check::<$solution>,
+            generate: generate::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(d01::Day01),
+    day!(d02::Day02),
+    day!(d03::Day03),
+    day!(d04::Day04),
+    day!(d05::Day05),
+    day!(d06::Day06),
+    day!(d07::Day07),
+    day!(d08::Day08),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
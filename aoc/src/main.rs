@@ -0,0 +1,427 @@
+mod args;
+mod days;
+mod scaffold;
+mod site;
+mod submit;
+mod summary;
+mod watch;
+
+use args::{Command, Options};
+use libaoc::alloc::{self, format_bytes};
+use days::{Day, DAYS};
+use libaoc::bench::format_duration;
+use libaoc::crypt::{self, Key};
+use libaoc::history::{self, Comparison, History, Record};
+use libaoc::input::{self, Input, InputError};
+use libaoc::ledger::{self, Ledger};
+use libaoc::{json, report, Format};
+use libaoc::ledger::Status;
+use site::{Fetched, Site};
+use submit::{Cooldown, Outcome};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command as Process;
+use std::{env, fs, process};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+fn main() {
+    let command = match args::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(msg) => {
+            eprintln!("{msg}\n\n{}", args::USAGE);
+            process::exit(2);
+        }
+    };
+
+    match run(command) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(msg) => {
+            eprintln!("{msg}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Runs `command`, returning `Ok(false)` if any answer disagreed with the ledger.
+fn run(command: Command) -> Result<bool, String> {
+    match command {
+        Command::Help => {
+            println!("{}", args::USAGE);
+            Ok(true)
+        }
+        Command::List => {
+            for day in DAYS {
+                println!("{}", day.name());
+            }
+            Ok(true)
+        }
+        Command::Run { day, part, options } => {
+            let day = days::find(day).ok_or_else(|| format!("No solution registered for day {day}"))?;
+            let input = input::read_input(day.dir, options.input.as_deref());
+            run_days(&[(day, input)], part, &options)
+        }
+        Command::All { options } => {
+            let name = options.input.as_deref().unwrap_or(input::DEFAULT_INPUT);
+            let days: Vec<_> = DAYS.iter().map(|day| (day, input::read_named(day.dir, name))).collect();
+            run_days(&days, None, &options)
+        }
+        Command::New { day, lib2d } => {
+            if days::find(day).is_some() {
+                return Err(format!("Day {day} is already registered"));
+            }
+            scaffold::new_day(Path::new(scaffold::WORKSPACE), day, lib2d)?;
+            println!("Created d{day:02}; fill in d{day:02}/example and d{day:02}/input to get started");
+            Ok(true)
+        }
+        Command::Check { day: Some(day), input } => {
+            let day = days::find(day).ok_or_else(|| format!("No solution registered for day {day}"))?;
+            check_day(day, &input::read_input(day.dir, input.as_deref()))
+        }
+        Command::Check { day: None, input } => {
+            let name = input.as_deref().unwrap_or(input::DEFAULT_INPUT);
+            let mut ok = true;
+            for day in DAYS {
+                ok &= check_day(day, &input::read_named(day.dir, name))?;
+            }
+            Ok(ok)
+        }
+        Command::Fetch { day } => {
+            let key = Key::load()?;
+            for day in select(day)? {
+                let path = Path::new(day.dir).join(input::DEFAULT_INPUT);
+                match site::fetch_input(&path, day.day, key.as_ref(), Site::from_env)? {
+                    Fetched::Cached => println!("{}: already have {}", day.name(), path.display()),
+                    Fetched::Downloaded(bytes) if key.is_some() => {
+                        println!("{}: saved {bytes} bytes, encrypted, to {}", day.name(), path.display())
+                    }
+                    Fetched::Downloaded(bytes) => println!("{}: saved {bytes} bytes to {}", day.name(), path.display()),
+                }
+            }
+            Ok(true)
+        }
+        Command::Keygen => {
+            let path = crypt::key_file();
+            let mut options = OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+            let mut file = options.open(&path).map_err(|err| match err.kind() {
+                io::ErrorKind::AlreadyExists => {
+                    format!("{path} already exists; inputs encrypted with it can't be read without it, so delete it yourself if you're sure")
+                }
+                _ => format!("Couldn't create {path}: {err}"),
+            })?;
+            writeln!(file, "{}", Key::generate().to_hex()).map_err(|err| format!("Couldn't write {path}: {err}"))?;
+            println!("Saved a new key to {path}; keep a copy somewhere safe");
+            Ok(true)
+        }
+        Command::Encrypt { day } => crypt_inputs(&select(day)?, true),
+        Command::Decrypt { day } => crypt_inputs(&select(day)?, false),
+        Command::Submit { day, part, input } => {
+            let day = days::find(day).ok_or_else(|| format!("No solution registered for day {day}"))?;
+            let input = input::read_input(day.dir, input.as_deref()).map_err(|err| err.to_string())?;
+            let solved = (day.solve)(&input.text, &[part]).map_err(|err| err.with_file(&input.source).to_string())?;
+            let answer = &solved.parts[0].answer;
+            let fingerprint = ledger::fingerprint(&input.text);
+            let mut ledger = Ledger::open()?;
+            let cooldown = Cooldown::beside(ledger.path());
+
+            println!("{} part {part}: {answer}", day.name());
+            match submit::submit(&mut ledger, &cooldown, Site::from_env, day.day, part, &fingerprint, answer)? {
+                Outcome::Right => {
+                    println!("That's the right answer!");
+                    Ok(true)
+                }
+                Outcome::Wrong { status, wait } => {
+                    let verdict = match status {
+                        Status::TooHigh => "it's too high",
+                        Status::TooLow => "it's too low",
+                        _ => "it's wrong",
+                    };
+                    println!("The site says {verdict}; wait {}s before trying again", wait.as_secs());
+                    Ok(false)
+                }
+                Outcome::TooSoon { wait } => Err(format!("Answered too recently; try again in {}s", wait.as_secs())),
+                Outcome::AlreadySolved => {
+                    println!("Already solved");
+                    Ok(true)
+                }
+            }
+        }
+        Command::Watch { day } => {
+            let day = days::find(day).ok_or_else(|| format!("No solution registered for day {day}"))?;
+            watch::watch(day)
+        }
+        Command::Generate { day, size, seed, output } => {
+            let day = days::find(day).ok_or_else(|| format!("No solution registered for day {day}"))?;
+            let input = (day.generate)(seed, size);
+            match output {
+                Some(path) => fs::write(&path, input).map_err(|err| format!("Couldn't write {path}: {err}"))?,
+                None => print!("{input}"),
+            }
+            Ok(true)
+        }
+    }
+}
+
+/// The day given, or every day.
+fn select(day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    match day {
+        Some(day) => Ok(vec![days::find(day).ok_or_else(|| format!("No solution registered for day {day}"))?]),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
+
+/// Encrypts (or decrypts) each day's input in place, skipping days that don't have one yet.
+fn crypt_inputs(days: &[&Day], encrypt: bool) -> Result<bool, String> {
+    let key = Key::load()?.ok_or_else(|| {
+        format!("No key: set ${} or ${}, or create one with `aoc keygen`", crypt::KEY_ENV, crypt::KEY_FILE_ENV)
+    })?;
+    for day in days {
+        let path = Path::new(day.dir).join(input::DEFAULT_INPUT);
+        if !fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+            println!("{}: no input yet", day.name());
+            continue;
+        }
+        let status = if encrypt {
+            if crypt::encrypt_file(&key, &path)? { "encrypted" } else { "already encrypted" }
+        } else if crypt::decrypt_file(&key, &path)? {
+            "decrypted"
+        } else {
+            "already plain"
+        };
+        println!("{}: {status} {}", day.name(), path.display());
+    }
+    Ok(true)
+}
+
+fn run_days(days: &[(&Day, Result<Input, InputError>)], part: Option<u8>, options: &Options) -> Result<bool, String> {
+    if let Some(iterations) = options.bench {
+        let mut history = History::open()?;
+        let revision = git_revision();
+        let build = history::build();
+        let mut ok = true;
+        for (day, input) in days {
+            ok &= bench_day(day, input, iterations, options, &mut history, &revision, &build)?;
+        }
+        return Ok(ok);
+    }
+    if options.alloc {
+        if !alloc::installed() {
+            return Err("Counting allocations needs a build with `--features alloc-stats`".to_string());
+        }
+        for (day, input) in days {
+            alloc_day(day, input, options.format)?;
+        }
+        return Ok(true);
+    }
+
+    let mut ledger = Ledger::open()?;
+    let ok = if options.parallel {
+        summary::run_parallel(days, &mut ledger, options)?
+    } else {
+        let mut ok = true;
+        for (day, input) in days {
+            ok &= run_day(day, part, input, &mut ledger, options)?;
+        }
+        ok
+    };
+    if options.accept {
+        ledger.save()?;
+    }
+    Ok(ok)
+}
+
+fn run_day(
+    day: &Day,
+    part: Option<u8>,
+    input: &Result<Input, InputError>,
+    ledger: &mut Ledger,
+    options: &Options,
+) -> Result<bool, String> {
+    if options.format == Format::Text {
+        println!("== {} ==", day.name());
+    }
+    let input = input.as_ref().map_err(|err| err.to_string())?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let solved = (day.solve)(&input.text, &parts).map_err(|err| err.with_file(&input.source).to_string())?;
+    let fingerprint = ledger::fingerprint(&input.text);
+    let ok = report(ledger, day.day, &fingerprint, &solved, options.format);
+    if options.accept {
+        for solved in &solved.parts {
+            ledger.accept(day.day, solved.part, &fingerprint, &solved.answer);
+        }
+    }
+    Ok(ok)
+}
+
+/// Prints every problem with `day`'s input, returning `Ok(false)` if there were any.
+fn check_day(day: &Day, input: &Result<Input, InputError>) -> Result<bool, String> {
+    let input = input.as_ref().map_err(|err| err.to_string())?;
+    let errors = (day.check)(&input.text);
+    for err in &errors {
+        println!("{}\n", err.clone().with_file(&input.source));
+    }
+    match errors.len() {
+        0 => println!("{}: {} ok", day.name(), input.source),
+        1 => println!("{}: {} has 1 problem", day.name(), input.source),
+        count => println!("{}: {} has {count} problems", day.name(), input.source),
+    }
+    Ok(errors.is_empty())
+}
+
+fn alloc_day(day: &Day, input: &Result<Input, InputError>, format: Format) -> Result<(), String> {
+    let input = input.as_ref().map_err(|err| err.to_string())?;
+    let results = (day.alloc)(&input.text).map_err(|err| err.with_file(&input.source).to_string())?;
+    if format == Format::Json {
+        let fingerprint = ledger::fingerprint(&input.text);
+        for (phase, stats) in results {
+            let record = json::Object::new()
+                .num("day", day.day)
+                .str("phase", &phase.to_string())
+                .num("allocations", stats.allocations)
+                .num("bytes", stats.bytes)
+                .num("peak_bytes", stats.peak_bytes)
+                .str("input", &fingerprint);
+            println!("{record}");
+        }
+        return Ok(());
+    }
+
+    println!("== {} allocations ==", day.name());
+    println!("{:<8}{:>12}{:>12}{:>12}", "phase", "count", "bytes", "peak");
+    for (phase, stats) in results {
+        println!(
+            "{:<8}{:>12}{:>12}{:>12}",
+            phase.to_string(),
+            stats.allocations,
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak_bytes)
+        );
+    }
+    Ok(())
+}
+
+/// Benchmarks `day`, appending the results to `history`. Returns `Ok(false)` if comparing and any
+/// phase was slower than its best on record.
+fn bench_day(
+    day: &Day,
+    input: &Result<Input, InputError>,
+    iterations: usize,
+    options: &Options,
+    history: &mut History,
+    revision: &str,
+    build: &str,
+) -> Result<bool, String> {
+    let input = input.as_ref().map_err(|err| err.to_string())?;
+    let results = (day.bench)(&input.text, iterations).map_err(|err| err.with_file(&input.source).to_string())?;
+    let fingerprint = ledger::fingerprint(&input.text);
+    let compared: Vec<Option<Comparison>> = results
+        .iter()
+        .map(|(phase, stats)| {
+            let threshold = options.compare?;
+            history.compare(day.day, *phase, build, &fingerprint, stats, threshold)
+        })
+        .collect();
+
+    if options.format == Format::Json {
+        for ((phase, stats), comparison) in results.iter().zip(&compared) {
+            let mut record = json::Object::new()
+                .num("day", day.day)
+                .str("phase", &phase.to_string())
+                .num("iterations", iterations as i128)
+                .num("min_ns", stats.min.as_nanos() as i128)
+                .num("median_ns", stats.median.as_nanos() as i128)
+                .num("p95_ns", stats.p95.as_nanos() as i128)
+                .str("input", &fingerprint)
+                .str("revision", revision)
+                .str("build", build);
+            if let Some(comparison) = comparison {
+                record = record
+                    .num("best_median_ns", comparison.best.stats.median.as_nanos() as i128)
+                    .str("best_revision", &comparison.best.revision)
+                    .str("verdict", if comparison.slowdown { "slower" } else { "ok" });
+            }
+            println!("{record}");
+        }
+    } else {
+        println!("== {} ({iterations} iterations) ==", day.name());
+        print!("{:<8}{:>12}{:>12}{:>12}", "phase", "min", "median", "p95");
+        if options.compare.is_some() {
+            print!("{:>12}{:>10}", "best", "change");
+        }
+        println!();
+        for ((phase, stats), comparison) in results.iter().zip(&compared) {
+            print!(
+                "{:<8}{:>12}{:>12}{:>12}",
+                phase.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.p95)
+            );
+            match comparison {
+                Some(comparison) => print!(
+                    "{:>12}{:>10}{}",
+                    format_duration(comparison.best.stats.median),
+                    format!("{:+.1}%", comparison.change),
+                    if comparison.slowdown { "  SLOWER" } else { "" }
+                ),
+                None if options.compare.is_some() => print!("{:>12}{:>10}", "-", "-"),
+                None => {}
+            }
+            println!();
+        }
+    }
+
+    let mut ok = true;
+    for ((phase, stats), comparison) in results.iter().zip(&compared) {
+        if let Some(Comparison { best, change, slowdown: true }) = comparison {
+            eprintln!(
+                "!!! SLOWER: {} {phase} median {} is {change:.1}% slower than {} at {} !!!",
+                day.name(),
+                format_duration(stats.median),
+                format_duration(best.stats.median),
+                best.revision
+            );
+            ok = false;
+        }
+    }
+
+    let records = results
+        .into_iter()
+        .map(|(phase, stats)| Record {
+            revision: revision.to_string(),
+            day: day.day,
+            phase,
+            stats,
+            fingerprint: fingerprint.clone(),
+            build: build.to_string(),
+        })
+        .collect();
+    history.append(records)?;
+    Ok(ok)
+}
+
+/// The workspace's git revision, marked `-dirty` if tracked files have uncommitted changes, or
+/// `unknown` outside a git checkout.
+fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        let output = Process::new("git").arg("-C").arg(scaffold::WORKSPACE).args(args).output().ok()?;
+        output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let Some(revision) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => format!("{revision}-dirty"),
+        _ => revision,
+    }
+}
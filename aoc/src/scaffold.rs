@@ -0,0 +1,220 @@
+use std::fs;
+use std::path::Path;
+
+/// The workspace this binary was built from, which `aoc new` adds days to.
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+const CARGO_TOML: &str = r#"[package]
+name = "dNN"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+libaoc = { path = "../libaoc" }
+"#;
+
+const LIB_RS: &str = r#"use libaoc::parse::ParseError;
+use libaoc::{Answer, Solution};
+
+mod generate;
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input = Vec<String>;
+    const DAY: u8 = N;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        lines.len().into()
+    }
+
+    fn part2(_lines: &Self::Input) -> Answer {
+        0.into()
+    }
+}
+"#;
+
+const GENERATE_RS: &str = r#"use crate::DayNN;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+impl Generate for DayNN {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` lines of random numbers; replace with something shaped like the real input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size).map(|_| format!("{}\n", rng.below(1000))).collect()
+    }
+}
+"#;
+
+const MAIN_RS: &str = "fn main() {
+    libaoc::main::<dNN::DayNN>();
+}
+";
+
+const ANSWERS_TEST: &str = "libaoc::answer_tests!(dNN::DayNN);
+";
+
+const ANSWERS_TOML: &str = "[example]
+# part1 =
+# part2 =
+";
+
+/// Parses a day given as `9`, `09` or `d09`.
+pub fn parse_day(name: &str) -> Result<u8, String> {
+    let digits = name.strip_prefix('d').unwrap_or(name);
+    match digits.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day {name:?}, expected something like d09")),
+    }
+}
+
+/// Creates the crate for `day` in the workspace at `root`, adds it to the workspace members and
+/// registers it with the runner.
+pub fn new_day(root: &Path, day: u8, lib2d: bool) -> Result<(), String> {
+    let name = format!("d{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("dNN", &name)
+            .replace("DayNN", &format!("Day{day:02}"))
+            .replace("= N;", &format!("= {day};"))
+    };
+    let mut cargo_toml = fill(CARGO_TOML);
+    if lib2d {
+        cargo_toml += "lib2d = { path = \"../lib2d\" }\n";
+    }
+
+    // update the shared files first, so nothing is left half-registered if one of them is unexpected
+    let members = edit(&root.join("Cargo.toml"), |text| add_member(text, &name))?;
+    let runner_deps = edit(&root.join("aoc/Cargo.toml"), |text| add_dependency(text, &name))?;
+    let registry = edit(&root.join("aoc/src/days.rs"), |text| register(text, day))?;
+
+    write(&dir.join("Cargo.toml"), &cargo_toml)?;
+    write(&dir.join("src/lib.rs"), &fill(LIB_RS))?;
+    write(&dir.join("src/generate.rs"), &fill(GENERATE_RS))?;
+    write(&dir.join("src/main.rs"), &fill(MAIN_RS))?;
+    write(&dir.join("tests/answers.rs"), &fill(ANSWERS_TEST))?;
+    write(&dir.join("answers.toml"), ANSWERS_TOML)?;
+    write(&dir.join("example"), "")?;
+    write(&dir.join("input"), "")?;
+
+    for (path, text) in [members, runner_deps, registry] {
+        write(&path, &text)?;
+    }
+    Ok(())
+}
+
+fn edit<F>(path: &Path, change: F) -> Result<(std::path::PathBuf, String), String>
+where F: Fn(&str) -> Option<String> {
+    let text = fs::read_to_string(path).map_err(|err| format!("Couldn't read {}: {err}", path.display()))?;
+    let text = change(&text).ok_or_else(|| format!("Couldn't find where to add the new day in {}", path.display()))?;
+    Ok((path.to_path_buf(), text))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| format!("Couldn't create {}: {err}", parent.display()))?;
+    }
+    fs::write(path, text).map_err(|err| format!("Couldn't write {}: {err}", path.display()))
+}
+
+/// Rewrites the workspace `members = [...]` list with `name` added at the end.
+fn add_member(text: &str, name: &str) -> Option<String> {
+    let start = text.find("members = [")? + "members = [".len();
+    let end = start + text[start..].find(']')?;
+    let mut members: Vec<&str> = text[start..end]
+        .split(',')
+        .map(|member| member.trim())
+        .filter(|member| !member.is_empty())
+        .collect();
+    let quoted = format!("\"{name}\"");
+    members.push(&quoted);
+    let list = members
+        .iter()
+        .map(|member| format!("    {member}"))
+        .collect::<Vec<_>>()
+        .join(",\n");
+    Some(format!("{}\n{list}\n{}", &text[..start], &text[end..]))
+}
+
+/// Adds a path dependency on `name` after the runner's last day dependency.
+fn add_dependency(text: &str, name: &str) -> Option<String> {
+    let last = text.lines().rfind(|line| line.starts_with('d') && line.contains("path = \"../d"))?;
+    let at = text.find(last)? + last.len();
+    Some(format!("{}\n{name} = {{ path = \"../{name}\" }}{}", &text[..at], &text[at..]))
+}
+
+/// Adds `day!(dNN::DayNN)` to the end of the `DAYS` registry.
+fn register(text: &str, day: u8) -> Option<String> {
+    let start = text.find("pub const DAYS")?;
+    let end = start + text[start..].find("];")?;
+    Some(format!("{}    day!(d{day:02}::Day{day:02}),\n{}", &text[..end], &text[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libaoc::scratch::ScratchDir;
+
+    #[test]
+    fn days() {
+        assert_eq!(parse_day("d09"), Ok(9));
+        assert_eq!(parse_day("12"), Ok(12));
+        assert!(parse_day("d26").is_err());
+        assert!(parse_day("dx").is_err());
+    }
+
+    #[test]
+    fn members() {
+        let text = "[workspace]\nresolver = \"2\"\nmembers = [\n    \"lib2d\",\n    \"d08\"\n]";
+        assert_eq!(
+            add_member(text, "d09").unwrap(),
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"lib2d\",\n    \"d08\",\n    \"d09\"\n]"
+        );
+    }
+
+    #[test]
+    fn runner() {
+        let deps = "[dependencies]\nlibaoc = { path = \"../libaoc\" }\nd08 = { path = \"../d08\" }\n";
+        assert_eq!(
+            add_dependency(deps, "d09").unwrap(),
+            "[dependencies]\nlibaoc = { path = \"../libaoc\" }\nd08 = { path = \"../d08\" }\nd09 = { path = \"../d09\" }\n"
+        );
+
+        let days = "pub const DAYS: &[Day] = &[\n    day!(d08::Day08),\n];\n";
+        assert_eq!(register(days, 9).unwrap(), "pub const DAYS: &[Day] = &[\n    day!(d08::Day08),\n    day!(d09::Day09),\n];\n");
+    }
+
+    #[test]
+    fn scaffold() {
+        let scratch = ScratchDir::new("scaffold");
+        let root = scratch.path();
+        write(&root.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"d01\"\n]").unwrap();
+        write(&root.join("aoc/Cargo.toml"), "[dependencies]\nd01 = { path = \"../d01\" }\n").unwrap();
+        write(&root.join("aoc/src/days.rs"), "pub const DAYS: &[Day] = &[\n    day!(d01::Day01),\n];\n").unwrap();
+
+        new_day(root, 2, true).unwrap();
+        let lib = fs::read_to_string(root.join("d02/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day02;") && lib.contains("const DAY: u8 = 2;"));
+        assert!(fs::read_to_string(root.join("d02/Cargo.toml")).unwrap().contains("lib2d"));
+        assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("\"d02\""));
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs")).unwrap().contains("day!(d02::Day02)"));
+        assert_eq!(fs::read_to_string(root.join("d02/input")).unwrap(), "");
+
+        assert!(new_day(root, 2, false).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use libaoc::crypt::{self, Key};
+use std::env;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::time::Duration;
+
+/// The adventofcode.com session cookie, copied from a logged-in browser.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Overrides where the site is, e.g. to point at a local stand-in server.
+pub const URL_ENV: &str = "AOC_URL";
+/// Which event's puzzles the days belong to.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+/// An email or repository URL added to the User-Agent, so the site's maintainers can get in touch.
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2025;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A logged-in connection to the Advent of Code site.
+pub struct Site {
+    base_url: String,
+    year: u16,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Site {
+    pub fn new(base_url: &str, year: u16, session: &str, contact: Option<&str>) -> Self {
+        let mut user_agent = concat!("aoc/", env!("CARGO_PKG_VERSION")).to_string();
+        if let Some(contact) = contact {
+            user_agent += &format!(" (+{contact})");
+        }
+        Site {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            year,
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).user_agent(&user_agent).build(),
+        }
+    }
+
+    /// Connects using `$AOC_SESSION`, `$AOC_URL`, `$AOC_YEAR` and `$AOC_CONTACT`.
+    pub fn from_env() -> Result<Self, String> {
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .filter(|session| !session.trim().is_empty())
+            .ok_or_else(|| format!("Set ${SESSION_ENV} to your adventofcode.com session cookie to talk to the site"))?;
+        let url = env::var(URL_ENV).unwrap_or_else(|_| DEFAULT_URL.to_string());
+        let year = match env::var(YEAR_ENV) {
+            Ok(year) => year.parse().map_err(|_| format!("Invalid ${YEAR_ENV} {year:?}"))?,
+            Err(_) => DEFAULT_YEAR,
+        };
+        Ok(Site::new(&url, year, &session, env::var(CONTACT_ENV).ok().as_deref()))
+    }
+
+    /// Downloads `day`'s puzzle input.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+        let request = self.agent.get(&url).set("Cookie", &format!("session={}", self.session));
+        self.respond(&url, day, request.call())
+    }
+
+    /// Submits `answer` for one part of `day`, returning the page the site responds with.
+    pub fn post_answer(&self, day: u8, part: u8, answer: &str) -> Result<String, String> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+        let request = self.agent.post(&url).set("Cookie", &format!("session={}", self.session));
+        self.respond(&url, day, request.send_form(&[("level", &part.to_string()), ("answer", answer)]))
+    }
+
+    fn respond(&self, url: &str, day: u8, result: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+        match result {
+            Ok(response) => read_body(response),
+            Err(ureq::Error::Status(400, _)) | Err(ureq::Error::Status(401, _)) => Err(format!(
+                "The site rejected the session in ${SESSION_ENV}; it has probably expired, so log in again and copy the new session cookie"
+            )),
+            Err(ureq::Error::Status(404, _)) => Err(format!("Day {day} of {} isn't available (yet?)", self.year)),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = read_body(response).unwrap_or_default();
+                Err(format!("Requesting {url} failed with status {code}: {}", body.trim()))
+            }
+            Err(err) => Err(format!("Couldn't reach {url}: {err}")),
+        }
+    }
+}
+
+fn read_body(response: ureq::Response) -> Result<String, String> {
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|err| format!("Couldn't read the response: {err}"))?;
+    Ok(body)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there, so the site wasn't asked for it.
+    Cached,
+    /// Downloaded this many bytes.
+    Downloaded(usize),
+}
+
+/// Makes sure `day`'s input is saved at `path`, only connecting to the site (with `connect`) if it
+/// isn't there yet. An empty file, as left by `aoc new`, doesn't count. Given a `key`, the input is
+/// saved encrypted.
+pub fn fetch_input<F>(path: &Path, day: u8, key: Option<&Key>, connect: F) -> Result<Fetched, String>
+where F: FnOnce() -> Result<Site, String> {
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let input = connect()?.input(day)?;
+    if input.is_empty() {
+        return Err(format!("The site sent an empty input for day {day}"));
+    }
+    let saved = match key {
+        Some(key) => crypt::encrypt(key, &input),
+        None => input.clone(),
+    };
+    fs::write(path, saved).map_err(|err| format!("Couldn't write {}: {err}", path.display()))?;
+    Ok(Fetched::Downloaded(input.len()))
+}
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// A stand-in for the site that answers each connection with the next canned response and
+    /// records the requests it got.
+    pub struct Server {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else { return };
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap_or(0);
+                    }
+                    request += &line;
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8_lossy(&content);
+                recorded.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+        Server { url, requests }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libaoc::scratch::ScratchDir;
+
+    #[test]
+    fn downloads_once() {
+        let server = mock::serve(vec![(200, "3-5\n\n4\n")]);
+        let dir = ScratchDir::new("site-once");
+        let path = dir.join("input");
+        let connect = || Ok(Site::new(&server.url, 2025, "abc123\n", Some("me@example.com")));
+
+        assert_eq!(fetch_input(&path, 5, None, connect), Ok(Fetched::Downloaded(7)));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3-5\n\n4\n");
+        assert_eq!(fetch_input(&path, 5, None, connect), Ok(Fetched::Cached));
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/5/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+        assert!(requests[0].contains(&format!("User-Agent: aoc/{} (+me@example.com)\r\n", env!("CARGO_PKG_VERSION"))));
+    }
+
+    #[test]
+    fn empty_file_is_not_cached() {
+        let server = mock::serve(vec![(200, "L68\n")]);
+        let dir = ScratchDir::new("site-empty");
+        let path = dir.join("input");
+        fs::write(&path, "").unwrap();
+        let fetched = fetch_input(&path, 1, None, || Ok(Site::new(&server.url, 2025, "abc", None)));
+        assert_eq!(fetched, Ok(Fetched::Downloaded(4)));
+    }
+
+    #[test]
+    fn encrypts_with_key() {
+        let server = mock::serve(vec![(200, "L68\n")]);
+        let dir = ScratchDir::new("site-encrypted");
+        let path = dir.join("input");
+        let key = Key::generate();
+        let fetched = fetch_input(&path, 1, Some(&key), || Ok(Site::new(&server.url, 2025, "abc", None)));
+        assert_eq!(fetched, Ok(Fetched::Downloaded(4)));
+        let saved = fs::read_to_string(&path).unwrap();
+        assert!(crypt::is_encrypted(&saved));
+        assert_eq!(crypt::decrypt(&key, &saved).unwrap(), "L68\n");
+    }
+
+    #[test]
+    fn expired_session() {
+        let server = mock::serve(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")]);
+        let dir = ScratchDir::new("site-expired");
+        let path = dir.join("input");
+        let err = fetch_input(&path, 1, None, || Ok(Site::new(&server.url, 2025, "stale", None))).unwrap_err();
+        assert!(err.contains("expired"), "{err}");
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn not_unlocked() {
+        let server = mock::serve(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+        let err = Site::new(&server.url, 2025, "abc", None).input(12).unwrap_err();
+        assert_eq!(err, "Day 12 of 2025 isn't available (yet?)");
+    }
+}
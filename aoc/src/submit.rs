@@ -0,0 +1,233 @@
+use crate::site::Site;
+use libaoc::ledger::{Ledger, Status};
+use libaoc::Answer;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How long to hold off after a wrong answer when the site doesn't say.
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Right,
+    /// Rejected as `Wrong`, `TooHigh` or `TooLow`, with how long the site wants before the next try.
+    Wrong { status: Status, wait: Duration },
+    /// An answer was submitted too recently, so this one wasn't checked.
+    TooSoon { wait: Duration },
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+}
+
+/// Makes sense of the page the site sends back for a submitted answer.
+pub fn parse_outcome(page: &str) -> Result<Outcome, String> {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        Ok(Outcome::Right)
+    } else if text.contains("That's not the right answer") {
+        let status = if text.contains("too high") {
+            Status::TooHigh
+        } else if text.contains("too low") {
+            Status::TooLow
+        } else {
+            Status::Wrong
+        };
+        Ok(Outcome::Wrong { status, wait: wait_time(&text).unwrap_or(DEFAULT_WAIT) })
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Outcome::TooSoon { wait: wait_time(&text).unwrap_or(DEFAULT_WAIT) })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::AlreadySolved)
+    } else {
+        Err(format!("Couldn't make sense of the site's response: {text}"))
+    }
+}
+
+/// The text of the page's `<article>` (or the whole page, if there isn't one) without its markup.
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 3s left to wait" or "please wait 5 minutes before trying again".
+fn wait_time(text: &str) -> Option<Duration> {
+    if let Some(left) = between(text, "You have ", " left to wait") {
+        let mut secs = 0;
+        for amount in left.split_whitespace() {
+            let (count, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let count: u64 = count.parse().ok()?;
+            secs += count * unit_secs(unit)?;
+        }
+        return Some(Duration::from_secs(secs));
+    }
+
+    let text = text.to_lowercase();
+    let wait = between(&text, "please wait ", " before trying again")?;
+    let (count, unit) = wait.split_once(' ')?;
+    let count = match count {
+        "a" | "an" | "one" => 1,
+        count => count.parse().ok()?,
+    };
+    Some(Duration::from_secs(count * unit_secs(unit)?))
+}
+
+fn unit_secs(unit: &str) -> Option<u64> {
+    match unit.trim_end_matches('s') {
+        "h" | "hour" => Some(3600),
+        "m" | "minute" => Some(60),
+        "" | "second" => Some(1),
+        _ => None,
+    }
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let rest = &text[text.find(start)? + start.len()..];
+    Some(&rest[..rest.find(end)?])
+}
+
+/// When the site will take another answer, kept beside the ledger so it holds across runs.
+pub struct Cooldown {
+    path: PathBuf,
+}
+
+impl Cooldown {
+    pub fn beside(ledger: &Path) -> Self {
+        Cooldown { path: ledger.with_extension("cooldown") }
+    }
+
+    /// How much longer to wait, if at all.
+    pub fn remaining(&self) -> Option<Duration> {
+        let until: u64 = fs::read_to_string(&self.path).ok()?.trim().parse().ok()?;
+        let until = UNIX_EPOCH + Duration::from_secs(until);
+        until.duration_since(SystemTime::now()).ok().filter(|wait| !wait.is_zero())
+    }
+
+    pub fn start(&self, wait: Duration) -> Result<(), String> {
+        let until = (SystemTime::now() + wait).duration_since(UNIX_EPOCH).unwrap_or_default();
+        let secs = until.as_secs() + u64::from(until.subsec_nanos() > 0);
+        fs::write(&self.path, format!("{secs}\n")).map_err(|err| format!("Couldn't write {}: {err}", self.path.display()))
+    }
+}
+
+/// Submits `answer` (connecting with `connect`) unless the ledger already says how the site will take
+/// it or the site asked us to wait, then records the outcome in the ledger and saves it.
+pub fn submit<F>(
+    ledger: &mut Ledger,
+    cooldown: &Cooldown,
+    connect: F,
+    day: u8,
+    part: u8,
+    fingerprint: &str,
+    answer: &Answer,
+) -> Result<Outcome, String>
+where
+    F: FnOnce() -> Result<Site, String>,
+{
+    if let Some(entry) = ledger.accepted(day, part, fingerprint) {
+        if entry.answer == answer.to_string() {
+            return Ok(Outcome::AlreadySolved);
+        }
+        return Err(format!("Day {day} part {part} was already solved with {}, not {answer}", entry.answer));
+    }
+    if let Some(entry) = ledger.known_wrong(day, part, fingerprint, answer) {
+        return Err(match entry.status {
+            Status::TooHigh => format!("Not submitting {answer}: the site already said {} was too high", entry.answer),
+            Status::TooLow => format!("Not submitting {answer}: the site already said {} was too low", entry.answer),
+            _ => format!("Not submitting {answer}: the site already said it was wrong"),
+        });
+    }
+    if let Some(wait) = cooldown.remaining() {
+        return Err(format!("The site asked us to wait before answering again; try again in {}s", wait.as_secs().max(1)));
+    }
+
+    let page = connect()?.post_answer(day, part, &answer.to_string())?;
+    let outcome = parse_outcome(&page)?;
+    match &outcome {
+        Outcome::Right => ledger.accept(day, part, fingerprint, answer),
+        Outcome::Wrong { status, wait } => {
+            ledger.reject(day, part, fingerprint, *status, answer);
+            cooldown.start(*wait)?;
+        }
+        Outcome::TooSoon { wait } => cooldown.start(*wait)?,
+        Outcome::AlreadySolved => {}
+    }
+    ledger.save()?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::site::mock;
+    use libaoc::scratch::ScratchDir;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn outcomes() {
+        let right = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+        assert_eq!(parse_outcome(&right), Ok(Outcome::Right));
+
+        let high = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2025/about\">about page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. <a href=\"/2025/day/5\">[Return to Day 5]</a>");
+        assert_eq!(parse_outcome(&high), Ok(Outcome::Wrong { status: Status::TooHigh, wait: Duration::from_secs(60) }));
+
+        let wrong = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait 5 minutes before trying again.");
+        assert_eq!(parse_outcome(&wrong), Ok(Outcome::Wrong { status: Status::Wrong, wait: Duration::from_secs(300) }));
+
+        let soon = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait. <a href=\"/2025/day/5\">[Return to Day 5]</a>");
+        assert_eq!(parse_outcome(&soon), Ok(Outcome::TooSoon { wait: Duration::from_secs(63) }));
+
+        let solved = page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2025/day/5\">[Return to Day 5]</a>");
+        assert_eq!(parse_outcome(&solved), Ok(Outcome::AlreadySolved));
+
+        assert!(parse_outcome("<html>Down for maintenance</html>").unwrap_err().contains("Down for maintenance"));
+    }
+
+    #[test]
+    fn guards_and_records() {
+        let dir = ScratchDir::new("submit");
+        let path = dir.join("answers.ledger");
+        let cooldown = Cooldown::beside(&path);
+
+        let high = page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.");
+        let right = page("That's the right answer!");
+        let server = mock::serve(vec![(200, high.leak()), (200, right.leak())]);
+        let connect = || Ok(Site::new(&server.url, 2025, "abc", None));
+        let mut ledger = Ledger::open_at(&path).unwrap();
+
+        let outcome = submit(&mut ledger, &cooldown, connect, 5, 1, "fp", &Answer::Int(800));
+        assert_eq!(outcome, Ok(Outcome::Wrong { status: Status::TooHigh, wait: Duration::from_secs(60) }));
+        assert!(server.requests.lock().unwrap()[0].ends_with("level=1&answer=800"));
+
+        // known to be too high: refused without asking the site
+        let err = submit(&mut ledger, &cooldown, connect, 5, 1, "fp", &Answer::Int(900)).unwrap_err();
+        assert_eq!(err, "Not submitting 900: the site already said 800 was too high");
+        // not known to be wrong, but still cooling down
+        let err = submit(&mut ledger, &cooldown, connect, 5, 1, "fp", &Answer::Int(737)).unwrap_err();
+        assert!(err.contains("try again in"), "{err}");
+        assert_eq!(server.requests.lock().unwrap().len(), 1);
+
+        fs::remove_file(&cooldown.path).unwrap();
+        assert_eq!(submit(&mut ledger, &cooldown, connect, 5, 1, "fp", &Answer::Int(737)), Ok(Outcome::Right));
+        assert_eq!(submit(&mut ledger, &cooldown, connect, 5, 1, "fp", &Answer::Int(737)), Ok(Outcome::AlreadySolved));
+        assert_eq!(server.requests.lock().unwrap().len(), 2);
+
+        let ledger = Ledger::open_at(&path).unwrap();
+        let statuses: Vec<_> = ledger.entries().iter().map(|e| (e.status, e.answer.as_str())).collect();
+        assert_eq!(statuses, vec![(Status::TooHigh, "800"), (Status::Accepted, "737")]);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::args::Options;
+use crate::days::Day;
+use libaoc::bench::format_duration;
+use libaoc::input::{Input, InputError};
+use libaoc::ledger::{self, Ledger, Verdict};
+use libaoc::{report, Format, Solved};
+use std::thread;
+use std::time::Instant;
+
+/// Solves every day on its own thread, then reports them all (as a table, or as JSON records) in
+/// day order. Returns `Ok(false)` if any day failed or disagreed with the ledger.
+pub fn run_parallel(
+    days: &[(&Day, Result<Input, InputError>)],
+    ledger: &mut Ledger,
+    options: &Options,
+) -> Result<bool, String> {
+    let start = Instant::now();
+    let results: Vec<Result<Solved, String>> = thread::scope(|scope| {
+        let handles: Vec<_> = days
+            .iter()
+            .map(|(day, input)| scope.spawn(move || solve(day, input)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|_| Err("panicked".to_string())))
+            .collect()
+    });
+    let wall_time = start.elapsed();
+
+    let mut ok = true;
+    if options.format == Format::Text {
+        println!("{:<5}{:<6}{:<20}{:>12}  status", "day", "part", "answer", "time");
+    }
+    for ((day, input), result) in days.iter().zip(&results) {
+        let (input, solved) = match (input, result) {
+            (Ok(input), Ok(solved)) => (input, solved),
+            (_, Err(err)) => {
+                ok = false;
+                eprintln!("{}: {err}", day.name());
+                if options.format == Format::Text {
+                    println!("{:<5}{:<6}{:<20}{:>12}  ERROR", day.name(), "-", "-", "-");
+                }
+                continue;
+            }
+            (Err(_), Ok(_)) => unreachable!("solve fails for unreadable inputs"),
+        };
+        let fingerprint = ledger::fingerprint(&input.text);
+
+        match options.format {
+            Format::Json => ok &= report(ledger, day.day, &fingerprint, solved, Format::Json),
+            Format::Text => {
+                for part in &solved.parts {
+                    let status = match ledger.check(day.day, part.part, &fingerprint, &part.answer) {
+                        Verdict::Match => "ok".to_string(),
+                        Verdict::Unknown => "new".to_string(),
+                        Verdict::Mismatch { expected } => {
+                            ok = false;
+                            format!("FAIL (expected {expected})")
+                        }
+                    };
+                    println!(
+                        "{:<5}{:<6}{:<20}{:>12}  {status}",
+                        day.name(),
+                        part.part,
+                        part.answer.to_string(),
+                        format_duration(part.elapsed)
+                    );
+                }
+            }
+        }
+        if options.accept {
+            for part in &solved.parts {
+                ledger.accept(day.day, part.part, &fingerprint, &part.answer);
+            }
+        }
+    }
+    if options.format == Format::Text {
+        println!("{} days in {}", days.len(), format_duration(wall_time));
+    }
+    Ok(ok)
+}
+
+fn solve(day: &Day, input: &Result<Input, InputError>) -> Result<Solved, String> {
+    let input = input.as_ref().map_err(|err| err.to_string())?;
+    (day.solve)(&input.text, &[1, 2]).map_err(|err| err.with_file(&input.source).to_string())
+}
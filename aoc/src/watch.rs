@@ -0,0 +1,188 @@
+use crate::days::Day;
+use crate::scaffold::WORKSPACE;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+/// How often to look for changes.
+const POLL: Duration = Duration::from_millis(500);
+
+/// The files each run solves, when they exist.
+const INPUTS: [&str; 2] = ["example", "input"];
+
+/// Each answer from a run, by input file and part.
+type Answers = BTreeMap<(&'static str, u8), String>;
+
+/// Modification times of every file being watched.
+type Stamps = BTreeMap<PathBuf, SystemTime>;
+
+/// Rebuilds and re-runs `day` against its example and input whenever its sources or those files
+/// change, printing how the answers differ from the last run. Only returns if it can't watch.
+pub fn watch(day: &Day) -> Result<bool, String> {
+    let dir = Path::new(day.dir);
+    let mut watched = vec![dir.join("src")];
+    watched.extend(INPUTS.iter().map(|name| dir.join(name)));
+
+    let mut stamps = None;
+    let mut previous = Answers::new();
+    loop {
+        let current = snapshot(&watched);
+        if stamps.as_ref() != Some(&current) {
+            match stamps.as_ref().and_then(|old| changed(old, &current)) {
+                Some(path) => println!("== {}: {} changed ==", day.name(), path.display()),
+                None => println!("== {}: watching {} ==", day.name(), dir.display()),
+            }
+            if let Some(answers) = solve(day)? {
+                for line in diff(&previous, &answers) {
+                    println!("{line}");
+                }
+                previous = answers;
+            }
+            stamps = Some(current);
+        }
+        thread::sleep(POLL);
+    }
+}
+
+/// Modification times of the files under `paths`, which may be files or directories.
+fn snapshot(paths: &[PathBuf]) -> Stamps {
+    let mut stamps = Stamps::new();
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(meta) = fs::metadata(&path) else { continue };
+        if meta.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = meta.modified() {
+            stamps.insert(path, modified);
+        }
+    }
+    stamps
+}
+
+/// A file that was modified, added or removed between two snapshots.
+fn changed<'a>(old: &'a Stamps, new: &'a Stamps) -> Option<&'a Path> {
+    let modified = new.iter().find(|(path, time)| old.get(*path) != Some(time));
+    let removed = old.keys().find(|path| !new.contains_key(*path));
+    modified.map(|(path, _)| path).or(removed).map(|path| path.as_path())
+}
+
+/// Builds the day's binary and runs it on each input file, or returns `None` if it doesn't build.
+/// The build's and the solver's own diagnostics go straight to stderr.
+fn solve(day: &Day) -> Result<Option<Answers>, String> {
+    let built = cargo(day, "build").status().map_err(|err| format!("Couldn't run cargo: {err}"))?;
+    if !built.success() {
+        println!("Build failed; waiting for changes");
+        return Ok(None);
+    }
+
+    let mut answers = Answers::new();
+    for name in INPUTS {
+        let path = Path::new(day.dir).join(name);
+        if !path.exists() {
+            continue;
+        }
+        let output = cargo(day, "run")
+            .arg("--")
+            .arg(&path)
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|err| format!("Couldn't run cargo: {err}"))?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let solved: Vec<(u8, String)> = stdout.lines().filter_map(parse_answer).collect();
+        if solved.is_empty() && !output.status.success() {
+            println!("{name}: failed");
+        }
+        answers.extend(solved.into_iter().map(|(part, answer)| ((name, part), answer)));
+    }
+    Ok(Some(answers))
+}
+
+fn cargo(day: &Day, subcommand: &str) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command
+        .args([subcommand, "--quiet", "--release", "--manifest-path"])
+        .arg(Path::new(WORKSPACE).join("Cargo.toml"))
+        .args(["-p", &day.name()]);
+    command
+}
+
+/// Reads a `Part 1: 737` line from a day's binary.
+fn parse_answer(line: &str) -> Option<(u8, String)> {
+    let (part, answer) = line.strip_prefix("Part ")?.split_once(": ")?;
+    Some((part.parse().ok()?, answer.to_string()))
+}
+
+/// One line per answer, noting any that differ from the previous run's.
+fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    let mut lines: Vec<String> = current
+        .iter()
+        .map(|((name, part), answer)| match previous.get(&(*name, *part)) {
+            Some(old) if old != answer => format!("{name} part {part}: {answer} (was {old})"),
+            None if !previous.is_empty() => format!("{name} part {part}: {answer} (new)"),
+            _ => format!("{name} part {part}: {answer}"),
+        })
+        .collect();
+    lines.extend(
+        previous
+            .iter()
+            .filter(|(key, _)| !current.contains_key(*key))
+            .map(|((name, part), old)| format!("{name} part {part}: no answer (was {old})")),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libaoc::scratch::ScratchDir;
+
+    fn answers(list: &[(&'static str, u8, &str)]) -> Answers {
+        list.iter().map(|&(name, part, answer)| ((name, part), answer.to_string())).collect()
+    }
+
+    #[test]
+    fn diffs() {
+        let first = answers(&[("example", 1, "3"), ("input", 1, "1118")]);
+        assert_eq!(diff(&Answers::new(), &first), vec!["example part 1: 3", "input part 1: 1118"]);
+
+        let second = answers(&[("example", 1, "3"), ("example", 2, "6"), ("input", 1, "1119")]);
+        assert_eq!(
+            diff(&first, &second),
+            vec!["example part 1: 3", "example part 2: 6 (new)", "input part 1: 1119 (was 1118)"]
+        );
+
+        let third = answers(&[("example", 1, "3")]);
+        assert_eq!(diff(&first, &third), vec!["example part 1: 3", "input part 1: no answer (was 1118)"]);
+    }
+
+    #[test]
+    fn answer_lines() {
+        assert_eq!(parse_answer("Part 2: 6289"), Some((2, "6289".to_string())));
+        assert_eq!(parse_answer("Part 1: a b"), Some((1, "a b".to_string())));
+        assert_eq!(parse_answer("== d01 =="), None);
+    }
+
+    #[test]
+    fn detects_changes() {
+        let dir = ScratchDir::new("watch");
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "one").unwrap();
+        let watched = [dir.join("src"), dir.join("input")];
+
+        let before = snapshot(&watched);
+        assert_eq!(before.len(), 1);
+        assert_eq!(changed(&before, &before), None);
+
+        fs::write(dir.join("input"), "L68").unwrap();
+        let after = snapshot(&watched);
+        assert_eq!(changed(&before, &after), Some(dir.join("input").as_path()));
+
+        fs::remove_file(dir.join("src/lib.rs")).unwrap();
+        let removed = snapshot(&watched);
+        assert_eq!(changed(&after, &removed), Some(dir.join("src/lib.rs").as_path()));
+    }
+}
@@ -0,0 +1,14 @@
+use crate::Day01;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+impl Generate for Day01 {
+    const DEFAULT_SIZE: usize = 4500;
+
+    /// `size` rotations of up to 999 clicks each, like the real input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{}{}\n", rng.pick(&['L', 'R']), rng.range(1..=999)))
+            .collect()
+    }
+}
@@ -0,0 +1,94 @@
+use libaoc::parse::{parse_token, ParseError};
+use libaoc::{trace, Answer, Solution};
+
+mod generate;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(i32, i32)>;
+    const DAY: u8 = 1;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        lint_rotations(input)
+    }
+
+    fn part1(rotations: &Self::Input) -> Answer {
+        let mut cur_val = 50;
+        let mut zero_count = 0;
+        for &(dir, turns) in rotations {
+            cur_val += dir * turns;
+            cur_val = pos_mod(cur_val, 100);
+            if cur_val == 0 {
+                zero_count += 1;
+            }
+        }
+
+        zero_count.into()
+    }
+
+    fn part2(rotations: &Self::Input) -> Answer {
+        let mut cur_val = 50;
+        let mut zero_count = 0;
+        for &(dir, turns) in rotations {
+            let prev_val = cur_val;
+            cur_val += dir * turns;
+            trace!("{:?} -> {:?} -> {:?}", prev_val, cur_val, pos_mod(cur_val, 100));
+            if cur_val < 1 && prev_val > 0{
+                zero_count += 1;
+                trace!("negative: {zero_count}");
+            }
+            let full_turns = cur_val.abs() / 100;
+            if full_turns > 0 {
+                zero_count += full_turns;
+                trace!("{full_turns} full turns: {zero_count}");
+            }
+            cur_val = pos_mod(cur_val, 100);
+        }
+
+        zero_count.into()
+    }
+}
+
+/// `val` modulo `modulo`, but always positive.
+pub fn pos_mod(val: i32, modulo: i32) -> i32 {
+    ((val % modulo) + modulo) % modulo
+}
+
+/// Every line that isn't an L or R followed by a number of turns, including empty ones.
+pub fn lint_rotations(input: &str) -> Vec<ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| read_rotation(idx + 1, line).err())
+        .collect()
+}
+
+/// Parses each rotation into (direction, turns), where L is -1 and R is 1.
+pub fn read_input(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| read_rotation(idx + 1, line))
+        .collect()
+}
+
+#[allow(clippy::iter_nth_zero)]
+fn read_rotation(line_no: usize, line: &str) -> Result<(i32, i32), ParseError> {
+    let Some(dir) = line.chars().nth(0) else {
+        return Err(ParseError::line(line_no, line, "expected a rotation like L68, got an empty line"));
+    };
+    let dir_i = match dir {
+        'L' => -1,
+        'R' => 1,
+        _ => return Err(ParseError::at_column(line_no, line, 0, "expected the rotation to start with L or R"))
+    };
+    let turns: i32 = parse_token(line_no, line, &line[dir.len_utf8()..])?;
+
+    Ok((dir_i, turns))
+}
@@ -0,0 +1 @@
+libaoc::answer_tests!(d01::Day01);
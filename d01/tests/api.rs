@@ -0,0 +1,17 @@
+use d01::{lint_rotations, read_input};
+
+#[test]
+fn rotations() {
+    assert_eq!(read_input("L68\nR48\n").unwrap(), vec![(-1, 68), (1, 48)]);
+}
+
+#[test]
+fn lint_reports_everything() {
+    let input = "L68\nX5\n\nR3\nRx\n";
+    let errors = lint_rotations(input);
+    let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+    assert_eq!(found, vec![(2, 1), (3, 1), (5, 2)]);
+    assert_eq!(errors[1].message, "expected a rotation like L68, got an empty line");
+    assert_eq!(read_input(input).unwrap_err().line, 2);
+    assert!(lint_rotations("L68\nR48\n").is_empty());
+}
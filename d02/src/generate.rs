@@ -0,0 +1,21 @@
+use crate::Day02;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+impl Generate for Day02 {
+    const DEFAULT_SIZE: usize = 32;
+
+    /// `size` ranges of IDs up to 10 digits long. The ranges are kept narrow (at most 100,000 IDs)
+    /// since both parts walk every ID in them.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let ranges: Vec<String> = (0..size)
+            .map(|_| {
+                let digits = rng.range(1..=10) as u32;
+                let lower = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+                let upper = lower + rng.range(0..=100_000);
+                format!("{lower}-{upper}")
+            })
+            .collect();
+        format!("{}\n", ranges.join(","))
+    }
+}
@@ -0,0 +1,110 @@
+use libaoc::parse::{parse_token, ParseError};
+use libaoc::{debug, Answer, Solution};
+
+mod generate;
+
+pub type Num = u64;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(Num, Num)>;
+    const DAY: u8 = 2;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_ranges(input)
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        lint_ranges(input)
+    }
+
+    fn part1(ranges: &Self::Input) -> Answer {
+        let mut sum = 0;
+        debug!("Range count: {:?}", ranges.len());
+
+        ranges.iter().for_each(|(lower, upper)| {
+            for number in *lower..=*upper {
+                let str_num = number.to_string();
+                if str_num.len() % 2 != 0 {
+                    continue;
+                }
+                let half_len = str_num.len() / 2;
+                let (first_half, second_half) = str_num.split_at(half_len);
+                if first_half == second_half {
+                    sum += number;
+                }
+            }
+        });
+
+        sum.into()
+    }
+
+    fn part2(ranges: &Self::Input) -> Answer {
+        let mut sum = 0;
+        ranges.iter().for_each(|(lower, upper)| {
+            'num_loop: for number in *lower..=*upper {
+                let str_num = number.to_string();
+                // for each potential pattern length, up to half of the number of digits
+                'pattern_loop: for pattern_len in 1..=(str_num.len() / 2) {
+                    // if the string's length is not divisible by the pattern's length, continue
+                    if str_num.len() % pattern_len != 0 {
+                        continue 'pattern_loop;
+                    }
+                    // get the first n digits of the number
+                    let substring = &str_num[0..pattern_len];
+                    // figure out how many times we'll need to repeat them
+                    let repeats = str_num.len() / pattern_len;
+                    // repeat them that many times & see if it matches our number string
+                    if substring.repeat(repeats) == str_num {
+                        sum += number;
+                        // if it does match, jump to the outer loop: 2222 should only be counted once,
+                        // not once as "2"x4 and once as "22"x2
+                        continue 'num_loop;
+                    }
+                }
+            }
+        });
+
+        sum.into()
+    }
+}
+
+/// Every item that isn't a `lower-upper` range of IDs with `lower <= upper`.
+pub fn lint_ranges(input: &str) -> Vec<ParseError> {
+    let mut errors = vec!();
+    let line = input.trim();
+    for range in line.split(",") {
+        let Some((lower, upper)) = range.split_once("-") else {
+            errors.push(ParseError::at(1, line, range, "expected a range like 11-22"));
+            continue;
+        };
+        match (parse_token::<Num>(1, line, lower), parse_token::<Num>(1, line, upper)) {
+            (Ok(lower), Ok(upper)) if lower > upper => {
+                errors.push(ParseError::at(1, line, range, "range ends before it begins"));
+            }
+            (lower, upper) => errors.extend(lower.err().into_iter().chain(upper.err())),
+        }
+    }
+    errors
+}
+
+/// Parses the comma-separated, inclusive `lower-upper` ID ranges.
+pub fn load_ranges(input: &str) -> Result<Vec<(Num, Num)>, ParseError> {
+    // the ranges are all on one line
+    let line = input.trim();
+    line
+        .split(",")
+        .map(|range| {
+            let Some((lower, upper)) = range.split_once("-") else {
+                return Err(ParseError::at(1, line, range, "expected a range like 11-22"));
+            };
+            let (lower, upper) = (parse_token(1, line, lower)?, parse_token(1, line, upper)?);
+            if lower > upper {
+                return Err(ParseError::at(1, line, range, "range ends before it begins"));
+            }
+            Ok((lower, upper))
+        })
+        .collect()
+}
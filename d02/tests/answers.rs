@@ -0,0 +1 @@
+libaoc::answer_tests!(d02::Day02);
@@ -0,0 +1,19 @@
+use d02::{lint_ranges, load_ranges};
+
+#[test]
+fn ranges() {
+    assert_eq!(load_ranges("11-22,95-115\n").unwrap(), vec![(11, 22), (95, 115)]);
+}
+
+#[test]
+fn lint_reports_everything() {
+    let input = "11-22,30-25,x-4,998\n";
+    let errors = lint_ranges(input);
+    let found: Vec<_> = errors.iter().map(|e| (e.column, e.message.as_str())).collect();
+    assert_eq!(found[0], (7, "range ends before it begins"));
+    assert_eq!(found[1].0, 13);
+    assert_eq!(found[2], (17, "expected a range like 11-22"));
+    assert_eq!(errors.len(), 3);
+    assert_eq!(load_ranges(input).unwrap_err().column, 7);
+    assert!(lint_ranges("11-22,22-22\n").is_empty());
+}
@@ -0,0 +1,17 @@
+use crate::Day03;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+impl Generate for Day03 {
+    const DEFAULT_SIZE: usize = 200;
+
+    /// `size` banks of 100 batteries rated 1 to 9.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            input.extend((0..100).map(|_| char::from(b'0' + rng.range(1..=9) as u8)));
+            input.push('\n');
+        }
+        input
+    }
+}
@@ -0,0 +1,126 @@
+use libaoc::parse::ParseError;
+use libaoc::{Answer, Solution};
+
+mod generate;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u32>>;
+    const DAY: u8 = 3;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_banks(input)
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        lint_banks(input)
+    }
+
+    fn part1(banks: &Self::Input) -> Answer {
+        let mut sum = 0;
+        for bank in banks.iter() {
+            let mut tens = 0;
+            let mut tens_idx = 0;
+            // all but the last digit
+            for (idx, &digit) in bank.split_last().unwrap().1.iter().enumerate() {
+                if digit > tens {
+                    tens = digit;
+                    tens_idx = idx;
+                }
+            }
+
+            let mut ones = 0;
+            for &digit in bank[(tens_idx + 1)..].iter() {
+                if digit > ones {
+                    ones = digit;
+                }
+            }
+
+            let bank_power = tens * 10 + ones;
+            sum += bank_power;
+        }
+
+        sum.into()
+    }
+
+    fn part2(banks: &Self::Input) -> Answer {
+        let mut sum = 0;
+        for bank in banks {
+            sum += jolt(bank, 12);
+        }
+
+        sum.into()
+    }
+}
+
+/// The largest number that can be made by picking `digit_count` batteries from `bank`, in order.
+pub fn jolt(bank: &[u32], digit_count: usize) -> u64 {
+    let mut banked_power: u64 = 0;
+    let mut prior_idx = 0;
+
+    for digit_idx in 0..digit_count {
+        // shift the current power over by 1 digit
+        banked_power *= 10;
+
+        // figure out how deep into the bank we can look for this digit
+        // (length - digits remaining)
+        let last_valid_index = bank.len() + digit_idx - digit_count;
+        // the valid range for this digit is (after the prior index) until (the last valid index)
+        let sub_bank = &bank[prior_idx..=last_valid_index];
+
+        let mut cur_digit = 0;
+        let mut sub_bank_idx = 0;
+
+        for (cur_idx, &digit) in sub_bank.iter().enumerate() {
+            if digit > cur_digit {
+                cur_digit = digit;
+                sub_bank_idx = cur_idx;
+            }
+        }
+        // add the largest digit in our valid range to the banked power
+        banked_power += cur_digit as u64;
+        // update the valid starting location for the next digit to be after this one
+        prior_idx += sub_bank_idx + 1;
+    }
+
+    banked_power
+}
+
+/// Every bank shorter than 12 batteries, and every character in a bank that isn't a digit.
+pub fn lint_banks(input: &str) -> Vec<ParseError> {
+    let mut errors = vec!();
+    for (idx, line) in input.lines().enumerate() {
+        if line.len() < 12 {
+            errors.push(ParseError::line(idx + 1, line, "expected a bank of at least 12 batteries"));
+        }
+        for (x, c) in line.chars().enumerate() {
+            if !c.is_ascii_digit() {
+                errors.push(ParseError::at_column(idx + 1, line, x, "expected a battery joltage digit"));
+            }
+        }
+    }
+    errors
+}
+
+/// Parses each line into a bank of battery joltages.
+pub fn load_banks(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            // part 2 needs at least 12 batteries to choose from
+            if line.len() < 12 {
+                return Err(ParseError::line(idx + 1, line, "expected a bank of at least 12 batteries"));
+            }
+            line.chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    c.to_digit(10)
+                        .ok_or_else(|| ParseError::at_column(idx + 1, line, x, "expected a battery joltage digit"))
+                })
+                .collect()
+        })
+        .collect()
+}
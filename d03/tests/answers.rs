@@ -0,0 +1 @@
+libaoc::answer_tests!(d03::Day03);
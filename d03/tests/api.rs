@@ -0,0 +1,19 @@
+use d03::{jolt, load_banks};
+
+#[test]
+fn jolt_picks_largest_digits_in_order() {
+    let banks = load_banks("987654321111111\n818181911112111\n").unwrap();
+    assert_eq!(jolt(&banks[0], 2), 98);
+    assert_eq!(jolt(&banks[1], 2), 92);
+    assert_eq!(jolt(&banks[0], 12), 987654321111);
+    assert_eq!(jolt(&banks[1], 12), 888911112111);
+}
+
+#[test]
+fn lint_reports_everything() {
+    let errors = d03::lint_banks("987654321111111\n98765\n9876543x1111a11\n");
+    let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+    assert_eq!(found, vec![(2, 1), (3, 8), (3, 13)]);
+    assert_eq!(errors[0].message, "expected a bank of at least 12 batteries");
+    assert!(d03::lint_banks("987654321111111\n").is_empty());
+}
@@ -0,0 +1,17 @@
+use crate::Day04;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+impl Generate for Day04 {
+    const DEFAULT_SIZE: usize = 137;
+
+    /// A `size` x `size` grid, with rolls of paper on about 60% of it like the real input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            input.extend((0..size).map(|_| if rng.chance(0.6) { '@' } else { '.' }));
+            input.push('\n');
+        }
+        input
+    }
+}
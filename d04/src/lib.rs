@@ -0,0 +1,72 @@
+use libaoc::parse::{check_grid, parse_grid, ParseError};
+use libaoc::{Answer, Solution};
+use lib2d::{Grid, Point2d};
+
+mod generate;
+
+pub type Coord = Point2d<i32>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<bool>;
+    const DAY: u8 = 4;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_grid(input)
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        check_grid(input, "@.")
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        let mut movable_rolls = 0;
+        for point in rolls(grid) {
+            if neighbor_rolls(grid, point) < 4 {
+                movable_rolls += 1;
+            }
+        }
+        movable_rolls.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        let mut movable_rolls = 0;
+        let mut grid = grid.clone();
+        loop {
+            let mut to_remove = vec!();
+            for point in rolls(&grid) {
+                if neighbor_rolls(&grid, point) < 4 {
+                    to_remove.push(point);
+                }
+            }
+            if to_remove.is_empty() {
+                break;
+            } else {
+                movable_rolls += to_remove.len();
+                for roll in to_remove {
+                    grid[roll] = false;
+                }
+            }
+        }
+        movable_rolls.into()
+    }
+}
+
+/// Parses the grid, marking the positions holding a roll of paper (`@`).
+pub fn load_grid(input: &str) -> Result<Grid<bool>, ParseError> {
+    parse_grid(input, "@.", |char| char == '@')
+}
+
+/// How many of the eight positions around `point` hold a roll of paper.
+fn neighbor_rolls(grid: &Grid<bool>, point: Coord) -> usize {
+    point.neighbors8_in(grid).filter(|&neighbor| grid[neighbor]).count()
+}
+
+/// The positions holding a roll of paper.
+fn rolls(grid: &Grid<bool>) -> impl Iterator<Item = Coord> + '_ {
+    grid.iter()
+        .filter(|(_, &roll)| roll)
+        .map(|(point, _)| Point2d::new(point.x as i32, point.y as i32))
+}
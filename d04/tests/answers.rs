@@ -0,0 +1 @@
+libaoc::answer_tests!(d04::Day04);
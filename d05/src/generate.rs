@@ -0,0 +1,25 @@
+use crate::Day05;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+const MAX_ID: u64 = 600_000_000_000_000;
+
+impl Generate for Day05 {
+    const DEFAULT_SIZE: usize = 180;
+
+    /// `size` fresh ranges spanning up to 10^13 IDs each, followed by five times as many
+    /// ingredient IDs to check.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let begin = rng.range(1..=MAX_ID);
+            let end = begin + rng.range(0..=10_000_000_000_000);
+            input.push_str(&format!("{begin}-{end}\n"));
+        }
+        input.push('\n');
+        for _ in 0..size * 5 {
+            input.push_str(&format!("{}\n", rng.range(1..=MAX_ID)));
+        }
+        input
+    }
+}
@@ -0,0 +1,155 @@
+use libaoc::parse::{parse_token, ParseError};
+use libaoc::{Answer, Solution};
+
+mod generate;
+
+pub type Ingredient = u64;
+
+/// An inclusive range of fresh ingredient IDs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Range {
+    pub begin: Ingredient,
+    pub end: Ingredient
+}
+
+impl Range {
+    pub fn new(start: Ingredient, end: Ingredient) -> Self {
+        Self { begin: start, end}
+    }
+
+    pub fn contains(self, &ingredient: &Ingredient) -> bool {
+        ingredient >= self.begin && ingredient <= self.end
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Range>, Vec<Ingredient>);
+    const DAY: u8 = 5;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_db(input)
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        lint_db(input)
+    }
+
+    fn part1((ranges, ingredients): &Self::Input) -> Answer {
+        let mut fresh_count = 0;
+        for ingredient in ingredients.iter() {
+            for range in ranges.iter() {
+                if range.contains(ingredient) {
+                    fresh_count += 1;
+                    break;
+                }
+            }
+        }
+
+        fresh_count.into()
+    }
+
+    fn part2((ranges, _): &Self::Input) -> Answer {
+        let mut ranges = ranges.clone();
+        ranges.sort_by_key(|range| range.begin);
+
+        let mut joined_ranges = vec!();
+        let mut iter = ranges.iter();
+        let mut cur_range = *iter.next().unwrap();
+        loop {
+            if let Some(next_range) = iter.next() {
+                if next_range.begin <= cur_range.end {
+                    if next_range.end > cur_range.end {
+                        cur_range = Range::new(cur_range.begin, next_range.end);
+                    }
+                } else {
+                    joined_ranges.push(cur_range);
+                    cur_range = *next_range;
+                }
+            } else {
+                joined_ranges.push(cur_range);
+                break;
+            }
+        }
+
+        let mut sum_size = 0;
+        for range in joined_ranges.iter() {
+            sum_size += range.end - range.begin + 1;
+        }
+        sum_size.into()
+    }
+}
+
+/// Every problem with the database: malformed or backwards ranges, IDs that aren't numbers, and a
+/// missing blank line between the two.
+pub fn lint_db(input: &str) -> Vec<ParseError> {
+    let mut errors = vec!();
+    let mut in_ranges = true;
+    let mut range_count = 0;
+    for (idx, line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        if !in_ranges {
+            errors.extend(parse_token::<Ingredient>(line_no, line, line).err());
+            continue;
+        }
+        if line.is_empty() {
+            in_ranges = false;
+            if range_count == 0 {
+                errors.push(ParseError::line(line_no, line, "expected at least one range"));
+            }
+            continue;
+        }
+
+        range_count += 1;
+        let Some((begin, end)) = line.split_once("-") else {
+            errors.push(ParseError::line(line_no, line, "expected a range like 3-5"));
+            continue;
+        };
+        match (parse_token::<Ingredient>(line_no, line, begin), parse_token::<Ingredient>(line_no, line, end)) {
+            (Ok(begin), Ok(end)) if begin > end => {
+                errors.push(ParseError::line(line_no, line, "range ends before it begins"));
+            }
+            (begin, end) => errors.extend(begin.err().into_iter().chain(end.err())),
+        }
+    }
+    if in_ranges {
+        errors.push(ParseError::end_of_input(input, "expected a blank line between the ranges and the ingredient IDs"));
+    }
+    errors
+}
+
+/// Parses the fresh ranges and the available ingredient IDs, which are separated by a blank line.
+pub fn load_db(input: &str) -> Result<(Vec<Range>, Vec<Ingredient>), ParseError> {
+    let mut lines = input.lines().enumerate();
+
+    // fresh ranges, up until the blank line
+    let mut ranges = vec!();
+    loop {
+        let Some((idx, line)) = lines.next() else {
+            return Err(ParseError::end_of_input(input, "expected a blank line between the ranges and the ingredient IDs"));
+        };
+        if line.is_empty() {
+            break;
+        }
+        let Some((begin, end)) = line.split_once("-") else {
+            return Err(ParseError::line(idx + 1, line, "expected a range like 3-5"));
+        };
+        let begin_incl: Ingredient = parse_token(idx + 1, line, begin)?;
+        let end_incl: Ingredient = parse_token(idx + 1, line, end)?;
+        if begin_incl > end_incl {
+            return Err(ParseError::line(idx + 1, line, "range ends before it begins"));
+        }
+        ranges.push(Range::new(begin_incl, end_incl));
+    }
+    if ranges.is_empty() {
+        return Err(ParseError::line(1, "", "expected at least one range"));
+    }
+
+    let ids = lines
+        .map(|(idx, line)| parse_token(idx + 1, line, line))
+        .collect::<Result<_, _>>()?;
+
+    Ok((ranges, ids))
+}
@@ -0,0 +1 @@
+libaoc::answer_tests!(d05::Day05);
@@ -0,0 +1,29 @@
+use d05::{load_db, Range};
+
+#[test]
+fn ranges_are_inclusive() {
+    let range = Range::new(3, 5);
+    assert!(range.contains(&3));
+    assert!(range.contains(&5));
+    assert!(!range.contains(&6));
+}
+
+#[test]
+fn load() {
+    let (ranges, ids) = load_db("3-5\n10-14\n\n1\n5\n").unwrap();
+    assert_eq!(ranges, vec![Range::new(3, 5), Range::new(10, 14)]);
+    assert_eq!(ids, vec![1, 5]);
+}
+
+#[test]
+fn lint_reports_everything() {
+    let errors = d05::lint_db("3-5\n9-2\nx-4\n\n1\ny\n");
+    let found: Vec<_> = errors.iter().map(|e| (e.line, e.message.as_str())).collect();
+    assert_eq!(found[0], (2, "range ends before it begins"));
+    assert_eq!(found[1].0, 3);
+    assert_eq!(found[2].0, 6);
+    assert_eq!(errors.len(), 3);
+
+    let errors = d05::lint_db("3-5\n1\n");
+    assert_eq!(errors.last().unwrap().message, "expected a blank line between the ranges and the ingredient IDs");
+}
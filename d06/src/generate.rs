@@ -0,0 +1,45 @@
+use crate::Day06;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+const ROWS: usize = 4;
+
+impl Generate for Day06 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` problems of four numbers each, every problem's numbers either all left- or all
+    /// right-aligned. Numbers have at most 3 digits, so part 2's column numbers have at most 4 and
+    /// the products stay well inside a `u64`. Each problem's numbers are ordered by length, so no
+    /// column has a gap between its digits.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut lines = vec![String::new(); ROWS + 1];
+        for problem in 0..size {
+            let mut numbers: Vec<String> = (0..ROWS)
+                .map(|_| {
+                    let digits = rng.range(1..=3) as u32;
+                    rng.range(1..=10u64.pow(digits) - 1).to_string()
+                })
+                .collect();
+            numbers.sort_by_key(|n| n.len());
+            if rng.chance(0.5) {
+                numbers.reverse();
+            }
+            let width = numbers.iter().map(|n| n.len()).max().unwrap();
+            let left = rng.chance(0.5);
+            let operator = rng.pick(&['+', '*']);
+
+            if problem > 0 {
+                lines.iter_mut().for_each(|line| line.push(' '));
+            }
+            for (line, number) in lines.iter_mut().zip(&numbers) {
+                if left {
+                    line.push_str(&format!("{number:<width$}"));
+                } else {
+                    line.push_str(&format!("{number:>width$}"));
+                }
+            }
+            lines[ROWS].push_str(&format!("{operator:<width$}"));
+        }
+        lines.iter().map(|line| format!("{line}\n")).collect()
+    }
+}
@@ -0,0 +1,210 @@
+use libaoc::parse::{parse_token, ParseError};
+use libaoc::{trace, Answer, Solution};
+
+mod generate;
+
+pub type Num = u64;
+
+/// A problem's operator and its numbers.
+pub type Problem = (String, Vec<Num>);
+
+pub struct Worksheet {
+    /// Each problem with its numbers read across the rows, for part 1.
+    pub rows: Vec<Problem>,
+    /// Each problem with its numbers read down the columns, right to left, for part 2.
+    pub columns: Vec<Problem>,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Worksheet;
+    const DAY: u8 = 6;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        if let Some(err) = check_worksheet(input).into_iter().next() {
+            return Err(err);
+        }
+        Ok(Worksheet { rows: load_math_1(input)?, columns: load_math_2(input)? })
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        check_worksheet(input)
+    }
+
+    fn part1(worksheet: &Self::Input) -> Answer {
+        grand_total(&worksheet.rows).into()
+    }
+
+    fn part2(worksheet: &Self::Input) -> Answer {
+        grand_total(&worksheet.columns).into()
+    }
+}
+
+fn grand_total(problems: &[Problem]) -> Num {
+    let mut sum: Num = 0;
+    for (symbol, numbers) in problems {
+        trace!("{symbol} {:?}", numbers);
+        match symbol.as_str() {
+            "+" => sum += numbers.iter().sum::<Num>(),
+            "*" => sum += numbers.iter().product::<Num>(),
+            _ => unreachable!("check_worksheet only allows + and *, got {symbol}")
+        }
+    }
+    sum
+}
+
+/// Makes sure the worksheet is something both parts can read: rows of numbers, all the same width,
+/// followed by a row with one + or * per problem, sitting under the problem's first column. Reports
+/// every problem found.
+pub fn check_worksheet(input: &str) -> Vec<ParseError> {
+    let mut errors = vec!();
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((&symbols, numbers)) = lines.split_last() else {
+        errors.push(ParseError::end_of_input(input, "expected rows of numbers followed by a row of operators"));
+        return errors;
+    };
+    if numbers.is_empty() {
+        errors.push(ParseError::line(1, symbols, "expected rows of numbers above the row of operators"));
+        return errors;
+    }
+    let symbols_line_no = lines.len();
+
+    for (x, char) in symbols.chars().enumerate() {
+        if !matches!(char, '+' | '*' | ' ') {
+            errors.push(ParseError::at_column(symbols_line_no, symbols, x, "expected + or *"));
+        }
+    }
+    let problem_count = symbols.split_whitespace().count();
+
+    let width = numbers[0].len();
+    for (idx, line) in numbers.iter().enumerate() {
+        let line_no = idx + 1;
+        for (x, char) in line.chars().enumerate() {
+            if !char.is_ascii_digit() && char != ' ' {
+                errors.push(ParseError::at_column(line_no, line, x, "expected a digit or a space"));
+            }
+        }
+        if line.len() != width {
+            errors.push(ParseError::line(line_no, line, format!("expected {width} columns like the first row, got {}", line.len())));
+        }
+        let count = line.split_whitespace().count();
+        if count != problem_count {
+            errors.push(ParseError::line(line_no, line, format!("expected {problem_count} numbers, one per operator, got {count}")));
+        }
+    }
+    if symbols.len() != width {
+        errors.push(ParseError::line(symbols_line_no, symbols, format!("expected {width} columns like the first row, got {}", symbols.len())));
+    }
+    if !errors.is_empty() {
+        // the columns can't be lined up reliably
+        return errors;
+    }
+
+    for (idx, line) in numbers.iter().enumerate() {
+        errors.extend(row_numbers(idx + 1, line).filter_map(Result::err));
+    }
+    errors.extend((0..width).filter_map(|x| column_number(numbers, x).err()));
+
+    // a column of nothing but spaces separates two problems
+    let blank: Vec<bool> = (0..width)
+        .map(|x| numbers.iter().all(|line| line.as_bytes()[x] == b' '))
+        .collect();
+    for (x, char) in symbols.chars().enumerate() {
+        let first_column = !blank[x] && (x == 0 || blank[x - 1]);
+        if char != ' ' && !first_column {
+            errors.push(ParseError::at_column(symbols_line_no, symbols, x, "expected operators only under the first column of a problem"));
+        } else if char == ' ' && first_column {
+            errors.push(ParseError::at_column(symbols_line_no, symbols, x, "expected an operator under the first column of this problem"));
+        }
+    }
+    errors.sort_by_key(|err| (err.line, err.column));
+    errors
+}
+
+/// Reads each problem as (operator, numbers), with each number written across a row.
+pub fn load_math_1(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let (numbers, symbols) = split_sheet(input)?;
+    let mut problems: Vec<Vec<Num>> = vec!();
+    for (idx, line) in numbers.iter().enumerate() {
+        for (problem, number) in row_numbers(idx + 1, line).enumerate() {
+            if problem == problems.len() {
+                problems.push(vec!());
+            }
+            problems[problem].push(number?);
+        }
+    }
+    with_operators(input, symbols.split_whitespace(), problems)
+}
+
+/// Reads each problem as (operator, numbers), with each number written down a column, right to left.
+pub fn load_math_2(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let (numbers, symbols) = split_sheet(input)?;
+    let width = numbers.iter().map(|line| line.len()).max().unwrap_or(0);
+
+    let mut problems = vec!();
+    let mut current_number_set = vec!();
+    for x in (0..width).rev() {
+        match column_number(&numbers, x)? {
+            Some(number) => current_number_set.push(number),
+            None if !current_number_set.is_empty() => problems.push(std::mem::take(&mut current_number_set)),
+            None => {}
+        }
+    }
+    if !current_number_set.is_empty() {
+        problems.push(current_number_set)
+    }
+    with_operators(input, symbols.split_whitespace().rev(), problems)
+}
+
+/// The rows of numbers, and the row of operators under them.
+fn split_sheet(input: &str) -> Result<(Vec<&str>, &str), ParseError> {
+    let mut numbers: Vec<&str> = input.lines().collect();
+    match numbers.pop() {
+        Some(symbols) if !numbers.is_empty() => Ok((numbers, symbols)),
+        _ => Err(ParseError::end_of_input(input, "expected rows of numbers followed by a row of operators")),
+    }
+}
+
+/// Pairs up each problem with its operator, which must be one each.
+fn with_operators<'a>(
+    input: &str,
+    symbols: impl Iterator<Item = &'a str>,
+    problems: Vec<Vec<Num>>,
+) -> Result<Vec<Problem>, ParseError> {
+    let symbols: Vec<String> = symbols.map(|s| s.to_string()).collect();
+    if symbols.len() != problems.len() {
+        let line_no = input.lines().count();
+        let line = input.lines().last().unwrap_or_default();
+        let message = format!("expected {} operators, one per problem, got {}", problems.len(), symbols.len());
+        return Err(ParseError::line(line_no, line, message));
+    }
+    Ok(symbols.into_iter().zip(problems).collect())
+}
+
+/// The numbers written across one row.
+fn row_numbers<'a>(line_no: usize, line: &'a str) -> impl Iterator<Item = Result<Num, ParseError>> + 'a {
+    line.split_whitespace().map(move |token| parse_token(line_no, line, token))
+}
+
+/// The number written down column `x`, top to bottom, or `None` if the column is blank.
+fn column_number(numbers: &[&str], x: usize) -> Result<Option<Num>, ParseError> {
+    let digits: Vec<(usize, char)> = numbers
+        .iter()
+        .enumerate()
+        .map(|(y, line)| (y, line.as_bytes().get(x).map_or(' ', |&byte| byte as char)))
+        .filter(|&(_, char)| char != ' ')
+        .collect();
+    let (Some(&(top, _)), Some(&(bottom, _))) = (digits.first(), digits.last()) else {
+        return Ok(None);
+    };
+    if bottom - top + 1 != digits.len() {
+        let gap = (top..bottom).find(|&y| digits.iter().all(|&(row, _)| row != y)).unwrap();
+        return Err(ParseError::at_column(gap + 1, numbers[gap], x, "expected no gaps between the digits down a column"));
+    }
+    let text: String = digits.iter().map(|&(_, char)| char).collect();
+    text.parse()
+        .map(Some)
+        .map_err(|err| ParseError::at_column(top + 1, numbers[top], x, format!("couldn't parse the column's number {text:?}: {err}")))
+}
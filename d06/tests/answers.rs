@@ -0,0 +1 @@
+libaoc::answer_tests!(d06::Day06);
@@ -0,0 +1,40 @@
+use d06::{check_worksheet, load_math_1, load_math_2};
+
+#[test]
+fn aligned_worksheet() {
+    assert!(check_worksheet("123 328\n 45 64 \n*   +  \n").is_empty());
+}
+
+#[test]
+fn misaligned_operators() {
+    let errors = check_worksheet("123 328\n 45 64 \n *  +  \n");
+    let columns: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+    assert_eq!(columns, vec![(3, 1), (3, 2)]);
+}
+
+#[test]
+fn reports_every_row() {
+    let errors = check_worksheet("1 2\n3x4\n5 6 7\n+ *\n");
+    let lines: Vec<_> = errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, vec![2, 2, 3, 3]);
+}
+
+#[test]
+fn column_with_a_gap() {
+    let input = "11\n2 \n33\n+ \n";
+    let errors = check_worksheet(input);
+    let found: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+    assert_eq!(found, vec![(2, 2)]);
+    assert_eq!(load_math_2(input).unwrap_err().line, 2);
+    assert_eq!(load_math_1(input).unwrap()[0].1, vec![11, 2, 33]);
+}
+
+#[test]
+fn number_too_big() {
+    let input = "99999999999999999999\n1                   \n+                   \n";
+    let errors = check_worksheet(input);
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!((errors[0].line, errors[0].column), (1, 1));
+    assert_eq!(load_math_1(input).unwrap_err().line, 1);
+    assert!(load_math_2(input).is_ok());
+}
@@ -0,0 +1,40 @@
+use crate::Day07;
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+impl Generate for Day07 {
+    const DEFAULT_SIZE: usize = 141;
+
+    /// A manifold `size` columns wide (at least 3) and one row deeper, with the start in the middle
+    /// of the top row. Like the real input, splitters only sit where a beam could reach them: on
+    /// every other row, alternating columns inside the triangle below the start, about 3 in 4 of
+    /// those spots filled and never in the edge columns.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = size.max(3);
+        let start = width / 2;
+        let mut input = String::new();
+        for row in 0..=width {
+            let depth = row / 2;
+            for col in 0..width {
+                let offset = col.abs_diff(start);
+                let cell = if row == 0 && col == start {
+                    'S'
+                } else if row > 0
+                    && row % 2 == 0
+                    && offset < depth
+                    && (offset + depth) % 2 == 1
+                    && col > 0
+                    && col < width - 1
+                    && rng.chance(0.73)
+                {
+                    '^'
+                } else {
+                    '.'
+                };
+                input.push(cell);
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
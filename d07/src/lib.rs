@@ -0,0 +1,180 @@
+use libaoc::parse::{check_grid, parse_grid, ParseError};
+use libaoc::{Answer, Solution};
+use lib2d::{Grid, Point2d};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+mod generate;
+
+pub type Loc = i32;
+pub type Pos = Point2d<Loc>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Start,
+    Splitter,
+}
+
+pub struct Layout {
+    pub start: Pos,
+    pub grid: Grid<Cell>
+}
+
+impl Layout {
+    fn is_splitter(&self, x: Loc, y: usize) -> bool {
+        self.grid.get(Point2d::new(x, y as Loc)) == Some(&Cell::Splitter)
+    }
+}
+
+/// A number of timelines, which outgrows every integer type on wide manifolds: base 10^18 digits,
+/// least significant first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timelines(Vec<u64>);
+
+const DIGIT: u64 = 1_000_000_000_000_000_000;
+
+impl Timelines {
+    pub fn one() -> Self {
+        Timelines(vec!(1))
+    }
+
+    pub fn add(&mut self, other: &Self) {
+        let mut carry = 0;
+        for idx in 0..self.0.len().max(other.0.len()) {
+            if idx == self.0.len() {
+                self.0.push(0);
+            }
+            let sum = self.0[idx] + other.0.get(idx).copied().unwrap_or(0) + carry;
+            self.0[idx] = sum % DIGIT;
+            carry = sum / DIGIT;
+        }
+        if carry > 0 {
+            self.0.push(carry);
+        }
+    }
+}
+
+impl Display for Timelines {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some((most, rest)) = self.0.split_last() else {
+            return write!(f, "0");
+        };
+        write!(f, "{most}")?;
+        for digit in rest.iter().rev() {
+            write!(f, "{digit:018}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A number while it fits in an `i128` (two base 10^18 digits always do), otherwise its decimal text.
+impl From<Timelines> for Answer {
+    fn from(timelines: Timelines) -> Self {
+        match timelines.0.as_slice() {
+            [] => Answer::Int(0),
+            [low] => Answer::Int(*low as i128),
+            [low, high] => Answer::Int(*high as i128 * DIGIT as i128 + *low as i128),
+            _ => Answer::Text(timelines.to_string()),
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Layout;
+    const DAY: u8 = 7;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_layout(input)
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        lint_layout(input)
+    }
+
+    fn part1(layout: &Self::Input) -> Answer {
+        let mut split_count = 0;
+
+        let mut beams_x = HashSet::new();
+        beams_x.insert(layout.start.x);
+        for row in 0..layout.grid.height() {
+            let mut new_beams_x = HashSet::new();
+            for old_beam in beams_x.iter() {
+                if layout.is_splitter(*old_beam, row) {
+                    new_beams_x.insert(old_beam - 1);
+                    new_beams_x.insert(old_beam + 1);
+                    split_count += 1;
+                } else {
+                    new_beams_x.insert(*old_beam);
+                }
+            }
+            beams_x = new_beams_x;
+        }
+        split_count.into()
+    }
+
+    fn part2(layout: &Self::Input) -> Answer {
+        let mut beams_x = HashMap::new();
+        beams_x.insert(layout.start.x, Timelines::one());
+        for row in 0..layout.grid.height() {
+            let mut new_beams_x: HashMap<Loc, Timelines> = HashMap::new();
+            for (old_beam, tl_count) in beams_x.iter() {
+                if layout.is_splitter(*old_beam, row) {
+                    for dx in [-1, 1] {
+                        new_beams_x.entry(old_beam + dx).or_default().add(tl_count);
+                    }
+                } else {
+                    new_beams_x.entry(*old_beam).or_default().add(tl_count);
+                }
+            }
+            beams_x = new_beams_x;
+        }
+        let mut timeline_sum = Timelines::default();
+        for tl_count in beams_x.values() {
+            timeline_sum.add(tl_count);
+        }
+        timeline_sum.into()
+    }
+}
+
+/// Every problem with the layout: ragged rows, unknown symbols, and anything but exactly one start.
+pub fn lint_layout(input: &str) -> Vec<ParseError> {
+    let mut errors = check_grid(input, ".S^");
+    let mut starts = 0;
+    for (y, line) in input.lines().enumerate() {
+        for (x, char) in line.chars().enumerate() {
+            if char == 'S' {
+                starts += 1;
+                if starts > 1 {
+                    errors.push(ParseError::at_column(y + 1, line, x, "found a second start position"));
+                }
+            }
+        }
+    }
+    if starts == 0 {
+        errors.push(ParseError::end_of_input(input, "never found the start position 'S'"));
+    }
+    errors.sort_by_key(|err| (err.line, err.column));
+    errors
+}
+
+/// Parses the manifold diagram into the beam's start and a grid of its cells.
+pub fn read_layout(input: &str) -> Result<Layout, ParseError> {
+    let grid = parse_grid(input, ".S^", |char| match char {
+        'S' => Cell::Start,
+        '^' => Cell::Splitter,
+        _ => Cell::Empty,
+    })?;
+
+    let starts: Vec<_> = grid.iter().filter(|(_, &cell)| cell == Cell::Start).map(|(point, _)| point).collect();
+    let start = *starts.first().ok_or_else(|| ParseError::end_of_input(input, "never found the start position 'S'"))?;
+    if let Some(second) = starts.get(1) {
+        let line = input.lines().nth(second.y).unwrap_or_default();
+        return Err(ParseError::at_column(second.y + 1, line, second.x, "found a second start position"));
+    }
+    let start = Point2d::new(start.x as Loc, start.y as Loc);
+    Ok(Layout {start, grid})
+}
\ No newline at end of file
@@ -0,0 +1 @@
+libaoc::answer_tests!(d07::Day07);
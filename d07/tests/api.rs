@@ -0,0 +1,24 @@
+use d07::{Day07, Timelines};
+use libaoc::Answer;
+
+#[test]
+fn timelines() {
+    let mut count = Timelines::one();
+    for _ in 0..70 {
+        let doubled = count.clone();
+        count.add(&doubled);
+    }
+    assert_eq!(count.to_string(), "1180591620717411303424");
+    assert_eq!(Answer::from(count), Answer::Int(1 << 70));
+    assert_eq!(Timelines::default().to_string(), "0");
+}
+
+#[test]
+fn very_wide_manifold() {
+    let input = libaoc::generate::<Day07>(1, Some(1000));
+    let solved = libaoc::solve::<Day07>(&input, &[2]).unwrap();
+    match &solved.parts[0].answer {
+        Answer::Text(digits) => assert!(digits.len() > 40 && digits.bytes().all(|b| b.is_ascii_digit()), "{digits}"),
+        other => panic!("Expected more timelines than fit in an i128, got {other}"),
+    }
+}
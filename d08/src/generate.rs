@@ -0,0 +1,17 @@
+use crate::{Day08, EXAMPLE_BOXES};
+use libaoc::rng::Rng;
+use libaoc::Generate;
+
+impl Generate for Day08 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` junction boxes with coordinates from 0 to 99,999. Only the example's 20 boxes (where
+    /// part 1 makes 10 connections) and 1000 or more (where it makes 1000) are valid inputs, so any
+    /// smaller `size` makes an example-sized input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = if size < 1000 { EXAMPLE_BOXES } else { size };
+        (0..size)
+            .map(|_| format!("{},{},{}\n", rng.below(100_000), rng.below(100_000), rng.below(100_000)))
+            .collect()
+    }
+}
@@ -0,0 +1,305 @@
+use libaoc::parse::{parse_token, ParseError};
+use libaoc::{debug, trace, Answer, Solution};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+mod generate;
+
+pub type Loc = i64;
+pub type Id = usize;
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Hash)]
+pub struct Point3d {
+    pub x: Loc,
+    pub y: Loc,
+    pub z: Loc,
+    /// the junction box's position in the input
+    pub id: Id
+}
+
+pub struct Game {
+    pub distinct_points: Vec<Point3d>,
+    /// every pair of points and the distance between them, closest first
+    pub sorted_distances: Vec<(Point3d, Point3d, f64)>,
+    /// how many of the closest pairs part 1 connects: 10 for the example's 20 boxes, 1000 for a real
+    /// input of 1000 or more (see [`connections_for`])
+    pub connections: usize
+}
+
+impl Point3d {
+    pub fn new(x: Loc, y: Loc, z: Loc, id: Id) -> Point3d {
+        Point3d {x, y, z, id}
+    }
+
+    /// Straight-line distance between the two points.
+    pub fn distance(&self, other: &Self) -> f64 {
+        let sq_dist = ((self.x - other.x).pow(2) +
+            (self.y - other.y).pow(2) +
+            (self.z - other.z).pow(2)) as f64;
+
+        sq_dist.sqrt()
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Game;
+    const DAY: u8 = 8;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        load_points(input)
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        lint_points(input)
+    }
+
+    fn part1(game: &Self::Input) -> Answer {
+        let mut circuit_map: HashMap<Id, Rc<RefCell<Vec<Id>>>> = HashMap::new();
+        let mut distinct_circuits = vec!();
+        for (point_a, point_b, _) in game.sorted_distances[..game.connections].iter() {
+            // get the nearest 2 points
+            trace!("A: {:?}", point_a);
+            trace!("B: {:?}", point_b);
+
+            // remove the circuit for each point from the map, if present
+            let point_a_circ = circuit_map.remove(&point_a.id);
+            let point_b_circ = circuit_map.remove(&point_b.id);
+
+            if let Some(point_a_circ) = point_a_circ {
+                if let Some(point_b_circ) = point_b_circ {
+                    if point_a_circ == point_b_circ {
+                        // they're already in the same circuit, easy peasy,
+                        // just put 'em back in the map
+                        trace!("  Both already in same circuit");
+                        circuit_map.insert(point_a.id, point_a_circ);
+                        circuit_map.insert(point_b.id, point_b_circ);
+                        continue;
+                    }
+                    // ugh, we need to merge the circuits
+                    // for every id in B's circuit, add that id to A's circuit,
+                    // then change the circuit map for that id to point at A's circuit instead
+                    trace!("  Both in different circuits");
+                    for id in point_b_circ.borrow().iter() {
+                        point_a_circ.borrow_mut().push(*id);
+                        circuit_map.insert(*id, Rc::clone(&point_a_circ));
+                    }
+                    circuit_map.insert(point_a.id, Rc::clone(&point_a_circ));
+                    point_b_circ.borrow_mut().clear();
+                } else {
+                    trace!("  A already in circuit");
+                    // point a is already in a circuit, but point b is not
+                    point_a_circ.borrow_mut().push(point_b.id);
+                    trace!("    A circuit: {:?}", point_a_circ);
+                    trace!("    Distinct circuits {:?}", distinct_circuits);
+                    circuit_map.insert(point_b.id, Rc::clone(&point_a_circ));
+                    circuit_map.insert(point_a.id, point_a_circ);
+                }
+            } else {
+                if let Some(point_b_circ) = point_b_circ {
+                    trace!("  B already in circuit");
+                    // point b is already in a circuit, but point a is not
+                    point_b_circ.borrow_mut().push(point_a.id);
+                    trace!("    A circuit: {:?}", point_b_circ);
+                    trace!("    Distinct circuits {:?}", distinct_circuits);
+                    circuit_map.insert(point_a.id, Rc::clone(&point_b_circ));
+                    circuit_map.insert(point_b.id, point_b_circ);
+                } else {
+                    trace!("  Creating new circuit");
+                    // neither point is already in a circuit, create a new one
+                    let new_circ = Rc::new(RefCell::new(vec!(point_a.id, point_b.id)));
+                    circuit_map.insert(point_a.id, Rc::clone(&new_circ));
+                    circuit_map.insert(point_b.id, Rc::clone(&new_circ));
+                    distinct_circuits.push(new_circ);
+                }
+            }
+        }
+
+        debug!("Distinct circuits: {:?}", distinct_circuits);
+
+        let mut circuit_lengths: Vec<usize> = distinct_circuits
+            .iter()
+            .map(|circuit| circuit.borrow().len())
+            .collect();
+        // boxes that were never connected are circuits of their own
+        let unconnected = game.distinct_points.len() - circuit_map.len();
+        circuit_lengths.extend(std::iter::repeat_n(1, unconnected));
+        circuit_lengths.sort_by(|a, b| b.cmp(a));
+
+        let multiplier = circuit_lengths[0] * circuit_lengths[1] * circuit_lengths[2];
+        multiplier.into()
+    }
+
+    fn part2(game: &Self::Input) -> Answer {
+        let mut circuit_map: HashMap<Id, Rc<RefCell<Vec<Id>>>> = HashMap::new();
+        let mut distinct_circuits = vec!();
+        for (point_a, point_b, _) in game.sorted_distances.iter() {
+            // get the nearest 2 points
+            trace!("A: {:?}", point_a);
+            trace!("B: {:?}", point_b);
+            let modified_circuit;
+
+            // remove the circuit for each point from the map, if present
+            let point_a_circ = circuit_map.remove(&point_a.id);
+            let point_b_circ = circuit_map.remove(&point_b.id);
+
+            if let Some(point_a_circ) = point_a_circ {
+                if let Some(point_b_circ) = point_b_circ {
+                    if point_a_circ == point_b_circ {
+                        // they're already in the same circuit, easy peasy,
+                        // just put 'em back in the map
+                        trace!("  Both already in same circuit");
+                        circuit_map.insert(point_a.id, point_a_circ);
+                        circuit_map.insert(point_b.id, point_b_circ);
+                        continue;
+                    }
+                    // ugh, we need to merge the circuits
+                    // for every id in B's circuit, add that id to A's circuit,
+                    // then change the circuit map for that id to point at A's circuit instead
+                    trace!("  Both in different circuits");
+                    for id in point_b_circ.borrow().iter() {
+                        point_a_circ.borrow_mut().push(*id);
+                        circuit_map.insert(*id, Rc::clone(&point_a_circ));
+                    }
+                    modified_circuit = Some(Rc::clone(&point_a_circ));
+                    circuit_map.insert(point_a.id, Rc::clone(&point_a_circ));
+                    point_b_circ.borrow_mut().clear();
+                } else {
+                    trace!("  A already in circuit");
+                    // point a is already in a circuit, but point b is not
+                    point_a_circ.borrow_mut().push(point_b.id);
+                    circuit_map.insert(point_b.id, Rc::clone(&point_a_circ));
+                    modified_circuit = Some(Rc::clone(&point_a_circ));
+                    circuit_map.insert(point_a.id, point_a_circ);
+                }
+            } else {
+                if let Some(point_b_circ) = point_b_circ {
+                    trace!("  B already in circuit");
+                    // point b is already in a circuit, but point a is not
+                    point_b_circ.borrow_mut().push(point_a.id);
+                    circuit_map.insert(point_a.id, Rc::clone(&point_b_circ));
+                    modified_circuit = Some(Rc::clone(&point_b_circ));
+                    circuit_map.insert(point_b.id, point_b_circ);
+                } else {
+                    trace!("  Creating new circuit");
+                    // neither point is already in a circuit, create a new one
+                    let new_circ = Rc::new(RefCell::new(vec!(point_a.id, point_b.id)));
+                    circuit_map.insert(point_a.id, Rc::clone(&new_circ));
+                    circuit_map.insert(point_b.id, Rc::clone(&new_circ));
+                    modified_circuit = Some(Rc::clone(&new_circ));
+                    distinct_circuits.push(new_circ);
+                }
+            }
+
+            if let Some(modified_circuit) = modified_circuit {
+                if modified_circuit.borrow().len() == game.distinct_points.len() {
+                    let x_dist = point_a.x * point_b.x;
+                    return x_dist.into();
+                }
+            }
+        }
+        panic!("Never connected every junction box into a single circuit");
+    }
+}
+
+/// How many junction boxes the example has.
+pub const EXAMPLE_BOXES: usize = 20;
+
+/// How many of the closest pairs part 1 connects for `boxes` junction boxes: the puzzle connects the
+/// 1000 closest pairs, but only 10 for the (much smaller) example. Any other count of boxes is
+/// neither, so it's `None` rather than a guess.
+pub fn connections_for(boxes: usize) -> Option<usize> {
+    match boxes {
+        EXAMPLE_BOXES => Some(10),
+        1000.. => Some(1000),
+        _ => None,
+    }
+}
+
+fn box_count_error(input: &str, boxes: usize) -> ParseError {
+    ParseError::end_of_input(
+        input,
+        format!("expected the example's {EXAMPLE_BOXES} junction boxes or at least 1000, got {boxes}"),
+    )
+}
+
+/// Every line that isn't exactly three integer coordinates, and a count of boxes that's neither the
+/// example's nor a real input's.
+pub fn lint_points(input: &str) -> Vec<ParseError> {
+    let mut errors = vec!();
+    for (idx, line) in input.lines().enumerate() {
+        let coords: Vec<&str> = line.split(",").collect();
+        if coords.len() != 3 {
+            errors.push(ParseError::line(idx + 1, line, format!("expected 3 coordinates, got {}", coords.len())));
+        }
+        errors.extend(coords.iter().filter_map(|coord| parse_token::<Loc>(idx + 1, line, coord).err()));
+    }
+    let boxes = input.lines().count();
+    if connections_for(boxes).is_none() {
+        errors.push(box_count_error(input, boxes));
+    }
+    errors
+}
+
+/// Parses the junction boxes and works out the distance between every pair of them.
+pub fn load_points(input: &str) -> Result<Game, ParseError> {
+    let points: Vec<Point3d> = input
+        .lines()
+        .enumerate()
+        .map(|(id, line)| {
+            let coords: Vec<Loc> = line
+                .split(",")
+                .map(|d| parse_token(id + 1, line, d))
+                .collect::<Result<_, _>>()?;
+            if coords.len() != 3 {
+                return Err(ParseError::line(id + 1, line, format!("expected 3 coordinates, got {}", coords.len())));
+            }
+            Ok(Point3d::new(coords[0], coords[1], coords[2], id))
+        })
+        .collect::<Result<_, _>>()?;
+    let connections = connections_for(points.len()).ok_or_else(|| box_count_error(input, points.len()))?;
+
+    let mut distances = vec!();
+
+    // for every point in the list
+    for (idx, point_a) in points.iter().enumerate() {
+        // for every _later_ point in the list
+        for point_b in points[(idx + 1)..].iter() {
+            // add the pair and their distance to the distance list
+            distances.push((*point_a, *point_b, point_a.distance(point_b)))
+        }
+    }
+    // sort the points by their distance apart
+    distances.sort_by(|a, b| a.2.total_cmp(&b.2));
+
+    if circuit_count(points.len(), &distances[..connections]) < 3 {
+        return Err(ParseError::end_of_input(input, format!("expected the {connections} closest pairs to leave at least 3 circuits")));
+    }
+
+    Ok(Game {distinct_points: points, sorted_distances: distances, connections})
+}
+
+/// How many circuits connecting each of `pairs` leaves, counting unconnected boxes as circuits of one.
+fn circuit_count(boxes: usize, pairs: &[(Point3d, Point3d, f64)]) -> usize {
+    fn root(parents: &mut [Id], mut id: Id) -> Id {
+        while parents[id] != id {
+            parents[id] = parents[parents[id]];
+            id = parents[id];
+        }
+        id
+    }
+
+    let mut parents: Vec<Id> = (0..boxes).collect();
+    let mut circuits = boxes;
+    for (point_a, point_b, _) in pairs {
+        let (root_a, root_b) = (root(&mut parents, point_a.id), root(&mut parents, point_b.id));
+        if root_a != root_b {
+            parents[root_a] = root_b;
+            circuits -= 1;
+        }
+    }
+    circuits
+}
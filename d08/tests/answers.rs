@@ -0,0 +1 @@
+libaoc::answer_tests!(d08::Day08);
@@ -0,0 +1,63 @@
+use d08::{load_points, Day08, Point3d};
+use libaoc::Answer;
+
+#[test]
+fn distance() {
+    let a = Point3d::new(0, 0, 0, 0);
+    let b = Point3d::new(2, 3, 6, 1);
+    assert_eq!(a.distance(&b), 7.0);
+    assert_eq!(b.distance(&a), 7.0);
+}
+
+#[test]
+fn pairs_sorted_by_distance() {
+    // five close together, then far-off boxes up to the example's 20
+    let far: String = (1..=15).map(|i| format!("{},0,5000\n", i * 1000)).collect();
+    let input = format!("0,0,0\n10,0,0\n1,0,0\n0,5,0\n0,0,9\n{far}");
+    let game = load_points(&input).unwrap();
+    assert_eq!(game.distinct_points.len(), 20);
+    assert_eq!(game.sorted_distances.len(), 190);
+    assert_eq!(game.connections, 10);
+    let (a, b, dist) = game.sorted_distances[0];
+    assert_eq!((a.id, b.id, dist), (0, 2, 1.0));
+    assert!(game.sorted_distances.windows(2).all(|w| w[0].2 <= w[1].2));
+}
+
+#[test]
+fn smallest_generated_inputs() {
+    for seed in 0..50 {
+        let input = libaoc::generate::<Day08>(seed, Some(0));
+        assert_eq!(input.lines().count(), 20);
+        let solved = libaoc::solve::<Day08>(&input, &[1]).unwrap();
+        assert!(matches!(solved.parts[0].answer, Answer::Int(n) if n >= 1), "seed {seed}");
+    }
+}
+
+#[test]
+fn only_example_or_real_sizes() {
+    assert_eq!(d08::connections_for(20), Some(10));
+    assert_eq!(d08::connections_for(999), None);
+    assert_eq!(d08::connections_for(1000), Some(1000));
+    assert_eq!(libaoc::generate::<Day08>(1, Some(999)).lines().count(), 20);
+
+    let input = "0,0,0\n10,0,0\n1,0,0\n0,5,0\n0,0,9\n";
+    let err = load_points(input).err().unwrap();
+    assert_eq!(err.message, "expected the example's 20 junction boxes or at least 1000, got 5");
+    assert_eq!(d08::lint_points(input), vec![err]);
+}
+
+#[test]
+fn too_few_circuits() {
+    // a line of boxes one apart: the 1000 closest pairs join them all into one circuit
+    let input: String = (0..1000).map(|x| format!("{x},0,0\n")).collect();
+    let err = load_points(&input).err().unwrap();
+    assert!(err.message.contains("at least 3 circuits"), "{err}");
+}
+
+#[test]
+fn lint_reports_every_line() {
+    let errors = d08::lint_points("1,2,3\n1,2\n4,x,6\n7,8,9,10\n");
+    let lines: Vec<_> = errors.iter().map(|e| e.line).collect();
+    // and four boxes are neither the example's nor a real input's
+    assert_eq!(lines, vec![2, 3, 4, 5]);
+}
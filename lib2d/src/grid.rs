@@ -0,0 +1,219 @@
+use crate::{Point2d, Rect};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A dense, row-major rectangle of cells, indexed by `Point2d` with `(0, 0)` at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// Row `y` is `len` cells long, but the first row is `width`.
+    Ragged { y: usize, width: usize, len: usize },
+    /// The character at `x`, `y` doesn't map to a cell.
+    Unexpected { x: usize, y: usize, char: char },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Ragged { y, width, len } => {
+                write!(f, "row {y} has {len} columns, but the first row has {width}")
+            }
+            GridError::Unexpected { x, y, char } => write!(f, "unexpected {char:?} at column {x} of row {y}"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Parses one row per line, turning each character into a cell with `cell`, which returns
+    /// `None` for characters that don't belong in the grid.
+    pub fn parse<F>(text: &str, mut cell: F) -> Result<Self, GridError>
+    where F: FnMut(char) -> Option<T> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(text.len());
+        for (y, line) in text.lines().enumerate() {
+            let start = cells.len();
+            for (x, char) in line.chars().enumerate() {
+                cells.push(cell(char).ok_or(GridError::Unexpected { x, y, char })?);
+            }
+            let len = cells.len() - start;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => return Err(GridError::Ragged { y, width, len }),
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Grid { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The rectangle covering every cell, or `None` if the grid has none.
+    pub fn bounds(&self) -> Option<Rect<usize>> {
+        (self.width > 0 && self.height > 0)
+            .then(|| Rect::new(Point2d::new(0, 0), Point2d::new(self.width - 1, self.height - 1)))
+    }
+
+    /// Whether `point` lies inside the grid.
+    pub fn contains<I>(&self, point: Point2d<I>) -> bool
+    where I: TryInto<usize> {
+        self.offset(point).is_some()
+    }
+
+    /// The cell at `point`, or `None` if it's outside the grid.
+    pub fn get<I>(&self, point: Point2d<I>) -> Option<&T>
+    where I: TryInto<usize> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut<I>(&mut self, point: Point2d<I>) -> Option<&mut T>
+    where I: TryInto<usize> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row from the top, including empty ones in a grid with no columns.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside a grid {} wide", self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2d<usize>, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, cell)| (Point2d::new(i % width, i / width), cell))
+    }
+
+    /// Draws the grid as text, one line per row, the way [`Grid::parse`] reads it.
+    pub fn render<F>(&self, mut char: F) -> String
+    where F: FnMut(&T) -> char {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut char));
+            text.push('\n');
+        }
+        text
+    }
+
+    fn offset<I>(&self, point: Point2d<I>) -> Option<usize>
+    where I: TryInto<usize> {
+        let x = point.x.try_into().ok().filter(|&x| x < self.width)?;
+        let y = point.y.try_into().ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+}
+
+impl<T, I> Index<Point2d<I>> for Grid<T>
+where I: TryInto<usize> + Copy + fmt::Debug {
+    type Output = T;
+
+    fn index(&self, point: Point2d<I>) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(point).unwrap_or_else(|| panic!("{point:?} is outside a {width}x{height} grid"))
+    }
+}
+
+impl<T, I> IndexMut<Point2d<I>> for Grid<T>
+where I: TryInto<usize> + Copy + fmt::Debug {
+    fn index_mut(&mut self, point: Point2d<I>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point).unwrap_or_else(|| panic!("{point:?} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Writes each cell's `Display` one row per line, so a `Grid<char>` prints back exactly the text it
+/// was parsed from.
+impl<T> Display for Grid<T>
+where T: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "ab.\n.c.\n";
+
+    #[test]
+    fn parse_and_display() {
+        let grid = Grid::parse(TEXT, Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), TEXT);
+        assert_eq!(grid.render(|&c| if c == '.' { ' ' } else { c }), "ab \n c \n");
+
+        let empty_rows = Grid::parse("\n\n", Some).unwrap();
+        assert_eq!((empty_rows.width(), empty_rows.height()), (0, 2));
+        assert_eq!(empty_rows.to_string(), "\n\n");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let digits = |c: char| c.to_digit(10);
+        assert_eq!(Grid::parse("12\n3x\n", digits), Err(GridError::Unexpected { x: 1, y: 1, char: 'x' }));
+        assert_eq!(Grid::parse("12\n345\n", digits), Err(GridError::Ragged { y: 1, width: 2, len: 3 }));
+        assert_eq!(Grid::parse("", digits).map(|grid| grid.iter().count()), Ok(0));
+    }
+
+    #[test]
+    fn lookups() {
+        let mut grid = Grid::parse(TEXT, Some).unwrap();
+        assert_eq!(grid[Point2d::new(1_usize, 1)], 'c');
+        assert_eq!(grid.get(Point2d::new(1_i32, 0)), Some(&'b'));
+        assert_eq!(grid.get(Point2d::new(-1_i32, 0)), None);
+        assert_eq!(grid.get(Point2d::new(3_i64, 0)), None);
+        assert!(!grid.contains(Point2d::new(0_u8, 2)));
+
+        grid[Point2d::new(2_i32, 1)] = 'd';
+        assert_eq!(grid.row(1), ['.', 'c', 'd']);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["a.", "bc", ".d"]);
+        let found: Vec<_> = grid.iter().filter(|(_, &c)| c == 'd').map(|(point, _)| point).collect();
+        assert_eq!(found, [Point2d::new(2, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside a 3x2 grid")]
+    fn index_out_of_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        let _ = grid[Point2d::new(0_i32, 2)];
+    }
+}
@@ -1,6 +1,14 @@
+mod grid;
+mod num;
+mod rect;
+
+pub use grid::{Grid, GridError};
+pub use num::{Num, Signed};
+pub use rect::Rect;
+
 use crate::Direction::*;
 use std::cmp::{max, min};
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub struct Point2d<T>
@@ -15,6 +23,50 @@ impl<T> Point2d<T> {
     }
 }
 
+impl<T: Signed> Point2d<T> {
+    /// Steps to get from here to `other` moving only orthogonally.
+    pub fn manhattan(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Steps to get from here to `other` when diagonal moves are allowed too.
+    pub fn chebyshev(self, other: Self) -> T {
+        max((self.x - other.x).abs(), (self.y - other.y).abs())
+    }
+
+    /// The four orthogonally adjacent points, clockwise from the one above.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |direction| self + direction.delta())
+    }
+
+    /// The eight surrounding points, including diagonals, clockwise from the one above.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL.into_iter().map(move |direction| self + direction.delta())
+    }
+
+    /// [`neighbors4`](Self::neighbors4) that lie inside `grid`.
+    pub fn neighbors4_in<C>(self, grid: &Grid<C>) -> impl Iterator<Item = Self> + '_
+    where T: TryInto<usize> {
+        self.neighbors4().filter(|&point| grid.contains(point))
+    }
+
+    /// [`neighbors8`](Self::neighbors8) that lie inside `grid`.
+    pub fn neighbors8_in<C>(self, grid: &Grid<C>) -> impl Iterator<Item = Self> + '_
+    where T: TryInto<usize> {
+        self.neighbors8().filter(|&point| grid.contains(point))
+    }
+
+    /// [`neighbors4`](Self::neighbors4) that lie inside `rect`.
+    pub fn neighbors4_within(self, rect: Rect<T>) -> impl Iterator<Item = Self> {
+        self.neighbors4().filter(move |&point| rect.contains(point))
+    }
+
+    /// [`neighbors8`](Self::neighbors8) that lie inside `rect`.
+    pub fn neighbors8_within(self, rect: Rect<T>) -> impl Iterator<Item = Self> {
+        self.neighbors8().filter(move |&point| rect.contains(point))
+    }
+}
+
 impl<T> Add for Point2d<T>
 where T: Add<Output = T> {
     type Output = Self;
@@ -33,6 +85,31 @@ where T: Sub<Output = T> {
     }
 }
 
+impl<T> AddAssign for Point2d<T>
+where T: AddAssign {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T> SubAssign for Point2d<T>
+where T: SubAssign {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> Neg for Point2d<T>
+where T: Neg<Output = T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {x: -self.x, y: -self.y}
+    }
+}
+
 impl<T> Mul<T> for Point2d<T>
 where T: Mul<Output = T> + Copy {
     type Output = Self;
@@ -51,27 +128,18 @@ where T: Div<Output = T> + Copy {
     }
 }
 
+/// Euclidean remainder of each coordinate, so points wrap around into `0..rhs.x` by `0..rhs.y`.
 impl<T> Rem<Point2d<T>> for Point2d<T>
-where T: Rem<Output = T> + Copy + std::cmp::PartialOrd<i32> + std::ops::AddAssign {
+where T: Signed {
     type Output = Self;
 
     fn rem(self, rhs: Point2d<T>) -> Self::Output {
-        let mut x = self.x % rhs.x;
-        let mut y = self.y % rhs.y;
-
-        if x < 0 {
-            x += rhs.x;
-        }
-
-        if y < 0 {
-            y += rhs.y;
-        }
-
-        Self { x, y }
+        Self { x: self.x.rem_euclid(rhs.x), y: self.y.rem_euclid(rhs.y) }
     }
 }
 
-pub fn corners<'a, T,C>(mut points: C) -> Option<(Point2d<T>, Point2d<T>)>
+/// The smallest rectangle holding all the `points`, or `None` if there aren't any.
+pub fn corners<'a, T,C>(mut points: C) -> Option<Rect<T>>
 where
     C: Iterator<Item = &'a Point2d<T>>,
     T: Ord + Copy + 'a
@@ -89,7 +157,7 @@ where
         max_y = max(point.y, max_y);
     }
 
-    Some((Point2d::new(min_x, min_y), Point2d::new(max_x, max_y)))
+    Some(Rect::new(Point2d::new(min_x, min_y), Point2d::new(max_x, max_y)))
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -97,24 +165,127 @@ pub enum Direction {
     Up, Down, Left, Right
 }
 
-pub fn dir_delta(direction: Direction) -> Point2d<i32> {
-    match direction {
-        Up => Point2d::new(0 , -1),
-        Down => Point2d::new(0, 1),
-        Left => Point2d::new(-1, 0),
-        Right => Point2d::new(1, 0),
+impl Direction {
+    /// Clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-1)
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_around(self) -> Self {
+        dir_opposite(self)
+    }
+
+    /// Turns clockwise by `steps` quarter turns, or anticlockwise if `steps` is negative.
+    pub fn rotate(self, steps: i32) -> Self {
+        let index = Self::ALL.iter().position(|&direction| direction == self).unwrap();
+        Self::ALL[(index as i32 + steps).rem_euclid(4) as usize]
+    }
+
+    /// One step in this direction, with `y` growing downwards.
+    pub fn delta<T>(self) -> Point2d<T>
+    where T: Signed {
+        Direction8::from(self).delta()
     }
 }
 
-pub fn dir_delta_64(direction: Direction) -> Point2d<i64> {
-    match direction {
-        Up => Point2d::new(0 , -1),
-        Down => Point2d::new(0, 1),
-        Left => Point2d::new(-1, 0),
-        Right => Point2d::new(1, 0),
+/// A compass direction including the diagonals, with `y` growing downwards.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction8 {
+    Up, UpRight, Right, DownRight, Down, DownLeft, Left, UpLeft
+}
+
+impl Direction8 {
+    /// Clockwise from `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up, Direction8::UpRight, Direction8::Right, Direction8::DownRight,
+        Direction8::Down, Direction8::DownLeft, Direction8::Left, Direction8::UpLeft,
+    ];
+    pub const CARDINAL: [Direction8; 4] = [Direction8::Up, Direction8::Right, Direction8::Down, Direction8::Left];
+    pub const DIAGONAL: [Direction8; 4] =
+        [Direction8::UpRight, Direction8::DownRight, Direction8::DownLeft, Direction8::UpLeft];
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn turn_around(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Turns clockwise by `steps` eighths of a turn (45° each), or anticlockwise if `steps` is
+    /// negative.
+    pub fn rotate(self, steps: i32) -> Self {
+        Self::ALL[(self as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// One step in this direction, e.g. `(1, -1)` for `UpRight`.
+    pub fn delta<T>(self) -> Point2d<T>
+    where T: Signed {
+        let (x, y) = match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        };
+        Point2d::new(T::from(x), T::from(y))
     }
 }
 
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Up => Direction8::Up,
+            Down => Direction8::Down,
+            Left => Direction8::Left,
+            Right => Direction8::Right,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    /// Fails, giving back the direction, for diagonals.
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        match direction {
+            Direction8::Up => Ok(Up),
+            Direction8::Down => Ok(Down),
+            Direction8::Left => Ok(Left),
+            Direction8::Right => Ok(Right),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+pub fn dir_delta<T: Signed>(direction: Direction) -> Point2d<T> {
+    direction.delta()
+}
+
+/// [`dir_delta`] for `i64`, from before it was generic.
+pub fn dir_delta_64(direction: Direction) -> Point2d<i64> {
+    dir_delta(direction)
+}
+
 pub fn dir_opposite(direction: Direction) -> Direction {
     match direction {
         Up => Down,
@@ -126,6 +297,7 @@ pub fn dir_opposite(direction: Direction) -> Direction {
 
 
 #[cfg(test)]
+#[allow(clippy::unnecessary_cast, clippy::useless_vec)]
 mod tests {
     use super::*;
 
@@ -173,13 +345,86 @@ mod tests {
         ];
         match corners(points.iter()) {
             None => panic!("Didn't get the corners"),
-            Some((top_left, bottom_right)) => {
+            Some(Rect { top_left, bottom_right }) => {
                 assert_eq!(top_left, Point2d::new(-1, -300));
                 assert_eq!(bottom_right, Point2d::new(1000, 2222))
             }
         };
     }
 
+    #[test]
+    fn neighbors() {
+        let point = Point2d::new(0, 0);
+        let around: Vec<_> = point.neighbors4().collect();
+        assert_eq!(around, [Point2d::new(0, -1), Point2d::new(1, 0), Point2d::new(0, 1), Point2d::new(-1, 0)]);
+        let around: Vec<_> = point.neighbors8().collect();
+        assert_eq!(around.len(), 8);
+        assert_eq!(around[1], Point2d::new(1, -1));
+        assert_eq!(around[7], Point2d::new(-1, -1));
+        assert!(!around.contains(&point));
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.turn_around(), Right);
+        assert_eq!(Down.rotate(-5), Right);
+        assert_eq!(Direction::ALL.map(|direction| direction.rotate(4)), Direction::ALL);
+
+        assert_eq!(Direction8::Up.rotate(1), Direction8::UpRight);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::Left);
+        assert_eq!(Direction8::DownRight.turn_around(), Direction8::UpLeft);
+        assert_eq!(Direction8::Right.rotate(-11), Direction8::UpLeft);
+        assert!(Direction8::DIAGONAL.iter().all(|direction| direction.is_diagonal()));
+        assert!(!Direction8::CARDINAL.iter().any(|direction| direction.is_diagonal()));
+    }
+
+    #[test]
+    fn deltas() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.delta::<i32>(), dir_delta(direction));
+            assert_eq!(direction.delta(), dir_delta_64(direction));
+            assert_eq!(Direction::try_from(Direction8::from(direction)), Ok(direction));
+        }
+        assert_eq!(Direction8::DownLeft.delta::<i128>(), Point2d::new(-1, 1));
+        assert_eq!(Direction::try_from(Direction8::UpLeft), Err(Direction8::UpLeft));
+        let sum = Direction8::ALL.iter().fold(Point2d::new(0_i16, 0), |sum, direction| sum + direction.delta());
+        assert_eq!(sum, Point2d::new(0, 0));
+    }
+
+    #[test]
+    fn bounded_neighbors() {
+        let grid = Grid::new(3, 2, ());
+        let corner = Point2d::new(0, 0);
+        assert_eq!(corner.neighbors4_in(&grid).collect::<Vec<_>>(), [Point2d::new(1, 0), Point2d::new(0, 1)]);
+        assert_eq!(corner.neighbors8_in(&grid).count(), 3);
+        assert_eq!(Point2d::new(1, 1).neighbors8_in(&grid).count(), 5);
+
+        let rect = Rect::new(Point2d::new(-1, -1), Point2d::new(0, 0));
+        assert_eq!(corner.neighbors4_within(rect).count(), 2);
+        assert_eq!(corner.neighbors8_within(rect).count(), 3);
+    }
+
+    #[test]
+    fn assign_and_neg() {
+        let mut a = Point2d::new(1_i64, 2);
+        a += Point2d::new(3, 4);
+        assert_eq!(a, Point2d::new(4, 6));
+        a -= Point2d::new(5, 5);
+        assert_eq!(-a, Point2d::new(1, -1));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point2d::new(1_i128, -2);
+        let b = Point2d::new(-3, 5);
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
     #[test]
     fn modulo() {
         let a = Point2d::new(-1, 15);
@@ -189,5 +434,9 @@ mod tests {
         // I want modulo to always give a positive answer
         assert_eq!(c.x, 2);
         assert_eq!(c.y, 3);
+
+        let wrapped = Point2d::new(-7_i64, 9_000_000_000) % Point2d::new(5, 7);
+        assert_eq!(wrapped, Point2d::new(3, 9_000_000_000 % 7));
+        assert_eq!(Point2d::new(-1_i128, -8) % Point2d::new(3, 4), Point2d::new(2, 0));
     }
 }
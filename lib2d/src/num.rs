@@ -0,0 +1,56 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign};
+
+/// The integer types, signed or not, for things like [`Rect`](crate::Rect) that work as well with a
+/// [`Grid`](crate::Grid)'s `usize` points as with signed ones.
+pub trait Num:
+    Copy
+    + 'static
+    + Debug
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+/// The signed integer types, so points, deltas and distances work the same whichever one a puzzle
+/// needs.
+pub trait Signed: Num + Div<Output = Self> + Rem<Output = Self> + Neg<Output = Self> + From<i8> {
+    fn abs(self) -> Self;
+
+    /// The remainder of dividing by `rhs`, never negative.
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! num {
+    ($($t:ty),*) => {
+        $(impl Num for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {
+        $(impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        })*
+    };
+}
+
+num!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+signed!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,160 @@
+use crate::{Num, Point2d, Signed};
+use std::cmp::{max, min};
+use std::iter;
+
+/// An axis-aligned rectangle, including both corners, as found by [`corners`](crate::corners).
+/// It's empty if `bottom_right` is above or left of `top_left`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub top_left: Point2d<T>,
+    pub bottom_right: Point2d<T>,
+}
+
+impl<T> Rect<T> {
+    pub fn new(top_left: Point2d<T>, bottom_right: Point2d<T>) -> Self {
+        Rect { top_left, bottom_right }
+    }
+}
+
+impl<T: Num> Rect<T> {
+    pub fn width(&self) -> T {
+        span_len(self.top_left.x, self.bottom_right.x)
+    }
+
+    pub fn height(&self) -> T {
+        span_len(self.top_left.y, self.bottom_right.y)
+    }
+
+    /// How many points are inside.
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.top_left.x > self.bottom_right.x || self.top_left.y > self.bottom_right.y
+    }
+
+    pub fn contains(&self, point: Point2d<T>) -> bool {
+        (self.top_left.x..=self.bottom_right.x).contains(&point.x)
+            && (self.top_left.y..=self.bottom_right.y).contains(&point.y)
+    }
+
+    /// The points inside both, if there are any.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let top_left = Point2d::new(max(self.top_left.x, other.top_left.x), max(self.top_left.y, other.top_left.y));
+        let bottom_right =
+            Point2d::new(min(self.bottom_right.x, other.bottom_right.x), min(self.bottom_right.y, other.bottom_right.y));
+        Some(Rect::new(top_left, bottom_right)).filter(|rect| !rect.is_empty())
+    }
+
+    /// The smallest rectangle holding both. An empty rectangle holds nothing, so it adds nothing.
+    pub fn union(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let top_left = Point2d::new(min(self.top_left.x, other.top_left.x), min(self.top_left.y, other.top_left.y));
+        let bottom_right =
+            Point2d::new(max(self.bottom_right.x, other.bottom_right.x), max(self.bottom_right.y, other.bottom_right.y));
+        Rect::new(top_left, bottom_right)
+    }
+
+    /// The point inside the rectangle closest to `point`. The rectangle mustn't be empty.
+    pub fn clamp(&self, point: Point2d<T>) -> Point2d<T> {
+        assert!(!self.is_empty(), "Can't clamp to an empty rectangle");
+        Point2d::new(
+            point.x.clamp(self.top_left.x, self.bottom_right.x),
+            point.y.clamp(self.top_left.y, self.bottom_right.y),
+        )
+    }
+
+    /// Every point inside, a row at a time from the top.
+    pub fn points(&self) -> impl Iterator<Item = Point2d<T>> {
+        let Rect { top_left, bottom_right } = *self;
+        span(top_left.y, bottom_right.y)
+            .flat_map(move |y| span(top_left.x, bottom_right.x).map(move |x| Point2d::new(x, y)))
+    }
+}
+
+impl<T: Signed> Rect<T> {
+    /// Grows every side outwards by `by`, or shrinks them if it's negative.
+    pub fn expand(&self, by: T) -> Self {
+        let by = Point2d::new(by, by);
+        Rect::new(self.top_left - by, self.bottom_right + by)
+    }
+}
+
+/// How many values `from..=to` holds, without going below zero for unsigned types.
+fn span_len<T: Num>(from: T, to: T) -> T {
+    if from > to { T::ZERO } else { to - from + T::ONE }
+}
+
+/// `from..=to`, for any `Num` (which `RangeInclusive` can't iterate over).
+fn span<T: Num>(from: T, to: T) -> impl Iterator<Item = T> {
+    iter::successors((from <= to).then_some(from), move |&n| (n < to).then(|| n + T::ONE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    fn rect(x1: i32, y1: i32, x2: i32, y2: i32) -> Rect<i32> {
+        Rect::new(Point2d::new(x1, y1), Point2d::new(x2, y2))
+    }
+
+    #[test]
+    fn size() {
+        let r = rect(-1, 2, 3, 4);
+        assert_eq!((r.width(), r.height(), r.area()), (5, 3, 15));
+        assert!(r.contains(Point2d::new(3, 2)));
+        assert!(!r.contains(Point2d::new(4, 2)));
+        assert_eq!(rect(1, 1, 0, 5).area(), 0);
+        assert!(rect(1, 1, 0, 5).is_empty());
+    }
+
+    #[test]
+    fn combine() {
+        let a = rect(0, 0, 4, 4);
+        let b = rect(3, -2, 6, 1);
+        assert_eq!(a.intersect(&b), Some(rect(3, 0, 4, 1)));
+        assert_eq!(a.intersect(&rect(5, 5, 6, 6)), None);
+        assert_eq!(a.union(&b), rect(0, -2, 6, 4));
+        let empty = rect(5, 5, 4, 4);
+        assert_eq!(empty.union(&rect(0, 0, 1, 1)), rect(0, 0, 1, 1));
+        assert_eq!(rect(0, 0, 1, 1).union(&empty), rect(0, 0, 1, 1));
+        assert_eq!(a.expand(1), rect(-1, -1, 5, 5));
+        assert!(a.expand(-3).is_empty());
+    }
+
+    #[test]
+    fn clamp() {
+        let r = rect(0, 0, 4, 4);
+        assert_eq!(r.clamp(Point2d::new(-3, 9)), Point2d::new(0, 4));
+        assert_eq!(r.clamp(Point2d::new(2, 3)), Point2d::new(2, 3));
+    }
+
+    #[test]
+    fn points() {
+        let found: Vec<_> = Rect::new(Point2d::new(1_i64, 0), Point2d::new(2, 1)).points().collect();
+        assert_eq!(found, [Point2d::new(1, 0), Point2d::new(2, 0), Point2d::new(1, 1), Point2d::new(2, 1)]);
+        assert_eq!(rect(0, 0, -1, 3).points().count(), 0);
+        let edge = Rect::new(Point2d::new(i8::MAX - 1, i8::MAX), Point2d::new(i8::MAX, i8::MAX));
+        assert_eq!(edge.points().count(), 2);
+    }
+
+    #[test]
+    fn unsigned() {
+        let grid = Grid::new(3, 2, '.');
+        let bounds = grid.bounds().unwrap();
+        assert_eq!((bounds.width(), bounds.height(), bounds.area()), (3, 2, 6));
+        assert!(bounds.points().all(|point| grid.contains(point)));
+        assert_eq!(bounds.clamp(Point2d::new(7, 0)), Point2d::new(2, 0));
+        let left = Rect::new(Point2d::new(0_usize, 0), Point2d::new(1, 1));
+        assert_eq!(bounds.intersect(&left), Some(left));
+        assert_eq!(Rect::new(Point2d::new(2_usize, 0), Point2d::new(1, 0)).area(), 0);
+        assert_eq!(Grid::new(0, 4, '.').bounds(), None);
+    }
+}
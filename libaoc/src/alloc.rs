@@ -0,0 +1,145 @@
+use crate::parse::ParseError;
+use crate::{Phase, Solution};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, counting every allocation. A binary opts in with
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: libaoc::alloc::Counting = libaoc::alloc::Counting;
+/// ```
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size() as u64, layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size() as u64, layout.size() as u64);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    /// Counts as one allocation of `new_size` bytes.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            let (old, new) = (layout.size() as u64, new_size as u64);
+            if new >= old {
+                grow(new, new - old);
+            } else {
+                grow(new, 0);
+                LIVE.fetch_sub(old - new, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+fn grow(allocated: u64, live: u64) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(allocated, Ordering::Relaxed);
+    let live = LIVE.fetch_add(live, Ordering::Relaxed) + live;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+/// Whether [`Counting`] is the global allocator, i.e. whether there is anything to measure.
+pub fn installed() -> bool {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(Box::new(0u64));
+    ALLOCATIONS.load(Ordering::Relaxed) != before
+}
+
+/// What one phase allocated. Allocations by other threads at the same time are counted too.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, reallocations included.
+    pub allocations: u64,
+    /// Total bytes requested, whether or not they were freed again.
+    pub bytes: u64,
+    /// The most bytes the phase had allocated at once, beyond what was live when it began.
+    pub peak_bytes: u64,
+}
+
+/// Allocation summary for each phase of a day, in [`Phase::ALL`] order.
+pub type AllocPhases = Vec<(Phase, AllocStats)>;
+
+/// Runs `f`, counting what it allocates.
+pub fn during<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let value = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (value, stats)
+}
+
+/// Counts the allocations made by parsing and each part of `S`. Only meaningful when [`Counting`]
+/// is the global allocator.
+pub fn measure<S: Solution>(input: &str) -> Result<AllocPhases, ParseError> {
+    let (parsed, parse) = during(|| S::parse(input));
+    let parsed = parsed?;
+    let (_, part1) = during(|| S::part1(&parsed));
+    let (_, part2) = during(|| S::part2(&parsed));
+    Ok(Phase::ALL.into_iter().zip([parse, part1, part2]).collect())
+}
+
+/// Formats `bytes` with a binary unit that keeps it readable, e.g. `912B`, `4.2KiB` or `1.5GiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1}{}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(912), "912B");
+        assert_eq!(format_bytes(4300), "4.2KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0GiB");
+    }
+
+    #[test]
+    fn not_installed() {
+        // the test harness uses the system allocator directly
+        assert!(!installed());
+        let (_, stats) = during(|| vec![1u8; 100]);
+        assert_eq!(stats, AllocStats { allocations: 0, bytes: 0, peak_bytes: 0 });
+    }
+}
@@ -0,0 +1,139 @@
+use crate::input::{self, InputError};
+use crate::{generate, solve, Answer, Generate, Solution};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Name of the per-day file recording the expected answer for each input.
+pub const MANIFEST: &str = "answers.toml";
+
+/// Expected answers, keyed by input name (`example`, `input`, ...) and then part.
+///
+/// The manifest is a small subset of TOML: one `[section]` per input, holding `part1`/`part2`
+/// entries whose values are either integers or quoted strings.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub inputs: BTreeMap<String, BTreeMap<u8, Answer>>,
+}
+
+impl Manifest {
+    pub fn load(dir: &str) -> Result<Manifest, String> {
+        let path = Path::new(dir).join(MANIFEST);
+        let text = fs::read_to_string(&path)
+            .map_err(|err| format!("Couldn't read {}: {err}", path.display()))?;
+        Manifest::parse(&text).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Manifest, String> {
+        let mut manifest = Manifest::default();
+        let mut section: Option<String> = None;
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                // an empty section still counts, e.g. an example whose answers aren't known yet
+                manifest.inputs.entry(name.trim().to_string()).or_default();
+                section = Some(name.trim().to_string());
+                continue;
+            }
+
+            let fail = |msg: &str| format!("line {}: {msg}: {line:?}", idx + 1);
+            let name = section.as_ref().ok_or_else(|| fail("entry outside of a [section]"))?;
+            let (key, value) = line.split_once('=').ok_or_else(|| fail("expected `key = value`"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(fail("expected part1 or part2")),
+            };
+            let value = value.trim();
+            let answer = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(text) => Answer::Text(text.to_string()),
+                None => Answer::Int(value.parse().map_err(|_| fail("expected an integer or a quoted string"))?),
+            };
+            manifest.inputs.entry(name.clone()).or_default().insert(part, answer);
+        }
+        Ok(manifest)
+    }
+}
+
+/// Solves the input `name` and asserts that every answer recorded for it in the manifest matches.
+///
+/// Inputs without a manifest section (or whose file is missing, or encrypted with no key to hand) are
+/// skipped unless `required`.
+pub fn check<S: Solution>(name: &str, required: bool) {
+    let manifest = Manifest::load(S::DIR).unwrap();
+    let Some(expected) = manifest.inputs.get(name) else {
+        assert!(!required, "{} has no [{name}] answers in {MANIFEST}", S::DIR);
+        return;
+    };
+
+    let input = match input::read_named(S::DIR, name) {
+        Ok(input) => input,
+        Err(InputError::NotFound { .. } | InputError::NoKey { .. }) if !required => return,
+        Err(err) => panic!("{err}"),
+    };
+
+    let parts: Vec<u8> = expected.keys().copied().collect();
+    let solved = solve::<S>(&input.text, &parts).unwrap_or_else(|err| panic!("{}", err.with_file(input.source)));
+    for solved in solved.parts {
+        let part = solved.part;
+        assert_eq!(solved.answer, expected[&part], "{}: wrong answer for {name} part {part}", S::DIR);
+    }
+}
+
+/// Checks that `S`'s generator makes inputs it can parse and solve, and that it is deterministic.
+pub fn check_generated<S: Generate>(size: usize) {
+    let input = generate::<S>(1, Some(size));
+    assert_eq!(input, generate::<S>(1, Some(size)), "{}: generator isn't deterministic", S::DIR);
+    if let Err(err) = solve::<S>(&input, &[1, 2]) {
+        panic!("{}: generated an invalid input\n{}", S::DIR, err.with_file("<generated>"));
+    }
+}
+
+/// Generates an `example` test (required) and an `input` test (skipped if absent) for a day,
+/// checked against the day's `answers.toml`, plus a `generated` test of the day's input generator.
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ty) => {
+        #[test]
+        fn example() {
+            $crate::answers::check::<$solution>("example", true);
+        }
+
+        #[test]
+        fn input() {
+            $crate::answers::check::<$solution>("input", false);
+        }
+
+        #[test]
+        fn generated() {
+            $crate::answers::check_generated::<$solution>(30);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let manifest = Manifest::parse("# comment\n[example]\npart1 = 3\npart2 = \"abc\"\n\n[input]\npart1 = -7\n").unwrap();
+        assert_eq!(manifest.inputs["example"][&1], Answer::Int(3));
+        assert_eq!(manifest.inputs["example"][&2], Answer::Text("abc".to_string()));
+        assert_eq!(manifest.inputs["input"][&1], Answer::Int(-7));
+        assert!(!manifest.inputs["input"].contains_key(&2));
+
+        let manifest = Manifest::parse("[example]\n# part1 =\n").unwrap();
+        assert!(manifest.inputs["example"].is_empty());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Manifest::parse("part1 = 3").is_err());
+        assert!(Manifest::parse("[example]\npart3 = 3").is_err());
+        assert!(Manifest::parse("[example]\npart1 = three").is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::parse::ParseError;
+use crate::{Phase, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary of the samples taken for one phase.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarizes `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        Stats { min: sorted[0], median, p95 }
+    }
+}
+
+/// Timing summary for each phase of a day, in [`Phase::ALL`] order.
+pub type PhaseStats = Vec<(Phase, Stats)>;
+
+/// Times parsing and each part of `S` separately, `iterations` times each after one warm-up run.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<PhaseStats, ParseError> {
+    assert!(iterations > 0, "Need at least one iteration to benchmark");
+    let parsed = S::parse(input)?;
+    black_box(S::part1(&parsed));
+    black_box(S::part2(&parsed));
+
+    let mut samples = [vec!(), vec!(), vec!()];
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        samples[2].push(start.elapsed());
+    }
+
+    Ok(Phase::ALL
+        .iter()
+        .zip(samples)
+        .map(|(&phase, samples)| (phase, Stats::from_samples(&samples)))
+        .collect())
+}
+
+/// Formats `duration` with a unit that keeps it readable, e.g. `812ns`, `4.21ms` or `1.05s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&n| Duration::from_millis(n)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&ms(&[5, 1, 3, 2, 4]));
+        assert_eq!(stats, Stats { min: Duration::from_millis(1), median: Duration::from_millis(3), p95: Duration::from_millis(5) });
+
+        let samples: Vec<u64> = (1..=100).rev().collect();
+        let stats = Stats::from_samples(&ms(&samples));
+        assert_eq!(stats.median, Duration::from_micros(50_500));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format_duration(Duration::from_nanos(812)), "812ns");
+        assert_eq!(format_duration(Duration::from_nanos(4_210_000)), "4.21ms");
+        assert_eq!(format_duration(Duration::from_millis(1050)), "1.05s");
+    }
+}
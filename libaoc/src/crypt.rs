@@ -0,0 +1,190 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+use std::{env, fs, io};
+
+/// Environment variable holding the key, as 64 hex digits.
+pub const KEY_ENV: &str = "AOC_KEY";
+
+/// Environment variable naming a file holding the key, used when `$AOC_KEY` isn't set.
+pub const KEY_FILE_ENV: &str = "AOC_KEY_FILE";
+
+/// Default key file: `.aoc-key` in the workspace root, which git ignores.
+pub const DEFAULT_KEY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../.aoc-key");
+
+/// First line of every encrypted file, so they can be told apart from plain ones.
+const MAGIC: &str = "aoc-encrypted v1";
+
+const NONCE_LEN: usize = 24;
+
+/// A 256-bit key for XChaCha20-Poly1305.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// A fresh random key from the operating system.
+    pub fn generate() -> Key {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Key, String> {
+        let bytes = from_hex(hex.trim()).filter(|bytes| bytes.len() == 32);
+        let bytes = bytes.ok_or("Expected the key to be 64 hex digits")?;
+        Ok(Key(bytes.try_into().unwrap()))
+    }
+
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.0)
+    }
+
+    /// The key from `$AOC_KEY`, or else from `$AOC_KEY_FILE` or the default key file, or `None`
+    /// if there isn't one.
+    pub fn load() -> Result<Option<Key>, String> {
+        if let Ok(hex) = env::var(KEY_ENV) {
+            return Key::from_hex(&hex).map(Some).map_err(|err| format!("Invalid ${KEY_ENV}: {err}"));
+        }
+        let path = key_file();
+        match fs::read_to_string(&path) {
+            Ok(hex) => Key::from_hex(&hex).map(Some).map_err(|err| format!("Invalid key in {path}: {err}")),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(format!("Couldn't read {path}: {err}")),
+        }
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+impl Debug for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+/// Where the key is kept when it isn't in `$AOC_KEY`: `$AOC_KEY_FILE`, or the default key file.
+pub fn key_file() -> String {
+    env::var(KEY_FILE_ENV).unwrap_or_else(|_| DEFAULT_KEY_FILE.to_string())
+}
+
+pub fn is_encrypted(text: &str) -> bool {
+    text.starts_with(MAGIC)
+}
+
+/// Encrypts `plain` with a random nonce, as hex text (so it still diffs and loads like any input).
+pub fn encrypt(key: &Key, plain: &str) -> String {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let sealed = key.cipher().encrypt(&nonce, plain.as_bytes()).expect("Encryption can't fail for in-memory data");
+
+    let mut bytes = nonce.to_vec();
+    bytes.extend(sealed);
+    let hex = to_hex(&bytes);
+    let mut text = format!("{MAGIC}\n");
+    for line in hex.as_bytes().chunks(64) {
+        text += std::str::from_utf8(line).unwrap();
+        text.push('\n');
+    }
+    text
+}
+
+/// Decrypts text made by [`encrypt`], failing if it was made with another key or has been altered.
+pub fn decrypt(key: &Key, text: &str) -> Result<String, String> {
+    let body = text.strip_prefix(MAGIC).ok_or("Not an encrypted input")?;
+    let hex: String = body.split_whitespace().collect();
+    let bytes = from_hex(&hex).filter(|bytes| bytes.len() >= NONCE_LEN).ok_or("Encrypted input is corrupted")?;
+    let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+    let plain = key
+        .cipher()
+        .decrypt(XNonce::from_slice(nonce), sealed)
+        .map_err(|_| "Couldn't decrypt: wrong key, or the file has been altered")?;
+    String::from_utf8(plain).map_err(|_| "Decrypted input isn't UTF-8".to_string())
+}
+
+/// Encrypts the file at `path` in place, returning `false` if it was already encrypted.
+pub fn encrypt_file(key: &Key, path: &Path) -> Result<bool, String> {
+    let text = fs::read_to_string(path).map_err(|err| format!("Couldn't read {}: {err}", path.display()))?;
+    if is_encrypted(&text) {
+        return Ok(false);
+    }
+    fs::write(path, encrypt(key, &text)).map_err(|err| format!("Couldn't write {}: {err}", path.display()))?;
+    Ok(true)
+}
+
+/// Decrypts the file at `path` in place, returning `false` if it wasn't encrypted.
+pub fn decrypt_file(key: &Key, path: &Path) -> Result<bool, String> {
+    let text = fs::read_to_string(path).map_err(|err| format!("Couldn't read {}: {err}", path.display()))?;
+    if !is_encrypted(&text) {
+        return Ok(false);
+    }
+    let plain = decrypt(key, &text).map_err(|err| format!("{}: {err}", path.display()))?;
+    fs::write(path, plain).map_err(|err| format!("Couldn't write {}: {err}", path.display()))?;
+    Ok(true)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    #[test]
+    fn round_trip() {
+        let key = Key::generate();
+        let plain = "3-5\n10-14\n\n1\n5\n";
+        let sealed = encrypt(&key, plain);
+        assert!(is_encrypted(&sealed));
+        assert!(!sealed.contains("10-14"));
+        assert!(sealed.lines().all(|line| line.len() <= 64));
+        assert_eq!(decrypt(&key, &sealed).unwrap(), plain);
+        // a fresh nonce every time
+        assert_ne!(encrypt(&key, plain), sealed);
+    }
+
+    #[test]
+    fn wrong_key_or_tampering() {
+        let key = Key::generate();
+        let sealed = encrypt(&key, "L68\n");
+        assert!(decrypt(&Key::generate(), &sealed).unwrap_err().contains("wrong key"));
+
+        let last = sealed.trim_end().chars().last().unwrap();
+        let flipped = if last == '0' { '1' } else { '0' };
+        let tampered = format!("{}{flipped}\n", &sealed.trim_end()[..sealed.trim_end().len() - 1]);
+        assert!(decrypt(&key, &tampered).is_err());
+        assert!(decrypt(&key, "L68\n").is_err());
+    }
+
+    #[test]
+    fn keys() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&format!("{}\n", key.to_hex())), Ok(key.clone()));
+        assert!(Key::from_hex("abcd").is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+        assert_eq!(format!("{key:?}"), "Key(..)");
+    }
+
+    #[test]
+    fn files() {
+        let dir = ScratchDir::new("crypt");
+        let path = dir.join("input");
+        fs::write(&path, "1,2,3\n").unwrap();
+        let key = Key::generate();
+
+        assert_eq!(encrypt_file(&key, &path), Ok(true));
+        assert_eq!(encrypt_file(&key, &path), Ok(false));
+        assert!(is_encrypted(&fs::read_to_string(&path).unwrap()));
+        assert_eq!(decrypt_file(&key, &path), Ok(true));
+        assert_eq!(decrypt_file(&key, &path), Ok(false));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+    }
+}
@@ -0,0 +1,205 @@
+use crate::bench::Stats;
+use crate::{alloc, store, Phase};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, io};
+
+/// Environment variable overriding where benchmark history is kept.
+pub const HISTORY_ENV: &str = "AOC_BENCH_HISTORY";
+
+/// Default history location: `bench.history` in the workspace root.
+pub const DEFAULT_HISTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../bench.history");
+
+const HEADER: &str = "# revision\tday\tphase\tmin ns\tmedian ns\tp95 ns\tinput fingerprint\tbuild\n";
+
+/// One phase's timings from one benchmark run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// The git revision the run was built from.
+    pub revision: String,
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+    pub fingerprint: String,
+    /// How the binary was built, from [`build`]. Timings are only compared between runs of the same
+    /// kind of build.
+    pub build: String,
+}
+
+/// How the running binary was built: `debug` or `release`, plus `+alloc-stats` when the counting
+/// allocator is installed, since it slows every allocation down.
+pub fn build() -> String {
+    let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+    if alloc::installed() { format!("{profile}+alloc-stats") } else { profile.to_string() }
+}
+
+/// How a fresh timing compares with the best one on record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub best: Record,
+    /// How much slower (positive) or faster (negative) the median is, in percent.
+    pub change: f64,
+    /// Slower than the best by more than the threshold.
+    pub slowdown: bool,
+}
+
+/// Slowdowns smaller than this are noise however large they are in percent.
+pub const MIN_SLOWDOWN: Duration = Duration::from_micros(1);
+
+/// Every benchmark run so far, one tab-separated record per phase per line. Records are only ever
+/// appended.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Opens the history named by `$AOC_BENCH_HISTORY`, or the default one in the workspace root.
+    pub fn open() -> Result<History, String> {
+        History::open_at(store::env_path(HISTORY_ENV, DEFAULT_HISTORY))
+    }
+
+    /// Opens the history at `path`; a missing file is an empty history.
+    pub fn open_at<P: AsRef<Path>>(path: P) -> Result<History, String> {
+        let path = path.as_ref().to_path_buf();
+        let records = store::load_lines(&path, "history record", parse_record)?;
+        Ok(History { path, records })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// The record with the lowest median for this phase of this day on the same input, from the
+    /// same kind of build.
+    pub fn best(&self, day: u8, phase: Phase, build: &str, fingerprint: &str) -> Option<&Record> {
+        self.records
+            .iter()
+            .filter(|r| r.day == day && r.phase == phase && r.build == build && r.fingerprint == fingerprint)
+            .min_by_key(|r| r.stats.median)
+    }
+
+    /// Compares `stats` with the best on record, flagging it if its median is more than
+    /// `threshold` percent slower.
+    pub fn compare(
+        &self,
+        day: u8,
+        phase: Phase,
+        build: &str,
+        fingerprint: &str,
+        stats: &Stats,
+        threshold: u32,
+    ) -> Option<Comparison> {
+        let best = self.best(day, phase, build, fingerprint)?;
+        let (now, then) = (stats.median.as_nanos() as f64, best.stats.median.as_nanos() as f64);
+        let change = if then == 0.0 { 0.0 } else { (now - then) / then * 100.0 };
+        let slowdown = change > threshold as f64 && stats.median.saturating_sub(best.stats.median) >= MIN_SLOWDOWN;
+        Some(Comparison { best: best.clone(), change, slowdown })
+    }
+
+    /// Appends `records` to the file, and to this history.
+    pub fn append(&mut self, records: Vec<Record>) -> Result<(), String> {
+        let fail = |err: io::Error| format!("Couldn't write {}: {err}", self.path.display());
+        let mut text = String::new();
+        if !self.path.exists() {
+            text += HEADER;
+        }
+        for r in &records {
+            text += &format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                r.revision,
+                r.day,
+                r.phase,
+                r.stats.min.as_nanos(),
+                r.stats.median.as_nanos(),
+                r.stats.p95.as_nanos(),
+                r.fingerprint,
+                r.build
+            );
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path).map_err(fail)?;
+        file.write_all(text.as_bytes()).map_err(fail)?;
+        self.records.extend(records);
+        Ok(())
+    }
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let mut fields = line.split('\t');
+    let revision = fields.next()?.to_string();
+    let day = fields.next()?.parse().ok()?;
+    let phase = fields.next()?;
+    let phase = Phase::ALL.into_iter().find(|p| p.to_string() == phase)?;
+    let mut nanos = || fields.next()?.parse().ok().map(Duration::from_nanos);
+    let stats = Stats { min: nanos()?, median: nanos()?, p95: nanos()? };
+    let fingerprint = fields.next()?.to_string();
+    // records from before builds were noted never match one
+    let build = fields.next().unwrap_or("unknown").to_string();
+    Some(Record { revision, day, phase, stats, fingerprint, build })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    fn record(revision: &str, phase: Phase, median_us: u64) -> Record {
+        let median = Duration::from_micros(median_us);
+        Record {
+            revision: revision.to_string(),
+            day: 4,
+            phase,
+            stats: Stats { min: median / 2, median, p95: median * 2 },
+            fingerprint: "abc".to_string(),
+            build: "release".to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = ScratchDir::new("history");
+        let path = dir.join("bench.history");
+
+        let mut history = History::open_at(&path).unwrap();
+        history.append(vec![record("1111111", Phase::Parse, 100), record("1111111", Phase::Part1, 50)]).unwrap();
+        history.append(vec![record("2222222-dirty", Phase::Parse, 80)]).unwrap();
+
+        let history = History::open_at(&path).unwrap();
+        assert_eq!(history.records().len(), 3);
+        assert_eq!(history.records()[1], record("1111111", Phase::Part1, 50));
+        assert_eq!(history.best(4, Phase::Parse, "release", "abc").unwrap().revision, "2222222-dirty");
+        assert_eq!(history.best(4, Phase::Parse, "release", "def"), None);
+        assert_eq!(history.best(4, Phase::Part2, "release", "abc"), None);
+        assert_eq!(history.best(4, Phase::Parse, "debug", "abc"), None);
+
+        let old = parse_record(&format!("1111111\t4\t{}\t1\t2\t3\tabc", Phase::Parse)).unwrap();
+        assert_eq!(old.build, "unknown");
+    }
+
+    #[test]
+    fn slowdowns() {
+        let dir = ScratchDir::new("history-never-written");
+        let mut history = History::open_at(dir.join("bench.history")).unwrap();
+        history.records.push(record("1111111", Phase::Part1, 100));
+
+        let stats = |us: u64| record("now", Phase::Part1, us).stats;
+        let compared = history.compare(4, Phase::Part1, "release", "abc", &stats(125), 10).unwrap();
+        assert_eq!((compared.change, compared.slowdown), (25.0, true));
+        assert!(!history.compare(4, Phase::Part1, "release", "abc", &stats(105), 10).unwrap().slowdown);
+        assert!(history.compare(4, Phase::Part1, "release", "abc", &stats(80), 10).unwrap().change < 0.0);
+        // a debug build is never compared with a release one
+        assert_eq!(history.compare(4, Phase::Part1, "debug", "abc", &stats(1000), 10), None);
+
+        // tiny phases are too noisy to flag
+        history.records.push(record("1111111", Phase::Parse, 0));
+        let tiny = Stats { min: Duration::ZERO, median: Duration::from_nanos(500), p95: Duration::from_nanos(900) };
+        assert!(!history.compare(4, Phase::Parse, "release", "abc", &tiny, 10).unwrap().slowdown);
+    }
+}
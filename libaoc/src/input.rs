@@ -0,0 +1,165 @@
+use crate::crypt::{self, Key};
+use std::fmt::{Display, Formatter};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// Environment variable naming the input to use when none is given on the command line.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Input file used when neither the command line nor the environment names one.
+pub const DEFAULT_INPUT: &str = "input";
+
+/// An input's contents, along with where they came from (for error messages).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub source: String,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { requested: String, tried: Vec<PathBuf> },
+    Io { path: PathBuf, err: io::Error },
+    Stdin(io::Error),
+    /// The file is encrypted, but there's no key to decrypt it with.
+    NoKey { path: PathBuf },
+    Decrypt { path: PathBuf, message: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::NotFound { requested, tried } => {
+                write!(f, "Couldn't find input {requested:?}; tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            InputError::Io { path, err } => write!(f, "Couldn't read {}: {err}", path.display()),
+            InputError::Stdin(err) => write!(f, "Couldn't read input from stdin: {err}"),
+            InputError::NoKey { path } => write!(
+                f,
+                "{} is encrypted; set ${} or ${} to the key (`aoc keygen` makes one)",
+                path.display(),
+                crypt::KEY_ENV,
+                crypt::KEY_FILE_ENV
+            ),
+            InputError::Decrypt { path, message } => write!(f, "{}: {message}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reads the input for the day whose crate lives in `dir`.
+///
+/// `arg` (usually from the command line) takes priority, then `$AOC_INPUT`, both read with
+/// [`read_given`]; otherwise it's `dir/input`, wherever the command is run from.
+pub fn read_input(dir: &str, arg: Option<&str>) -> Result<Input, InputError> {
+    match arg {
+        Some(arg) => read_given(dir, arg),
+        None => match env::var(INPUT_ENV) {
+            Ok(name) => read_given(dir, &name),
+            Err(_) => read_named(dir, DEFAULT_INPUT),
+        },
+    }
+}
+
+/// Reads a name the user gave: stdin if it is `-`, otherwise the path as given, or if that doesn't
+/// exist from the current directory, relative to `dir`, so `example` works from anywhere.
+pub fn read_given(dir: &str, name: &str) -> Result<Input, InputError> {
+    if name == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(InputError::Stdin)?;
+        return Ok(Input { source: "<stdin>".to_string(), text: input });
+    }
+    let path = Path::new(name);
+    if path.is_absolute() {
+        return read_first(name, vec![path.to_path_buf()], Key::load);
+    }
+    read_first(name, vec![path.to_path_buf(), Path::new(dir).join(path)], Key::load)
+}
+
+/// Reads `name` from the day's own directory `dir`, never the current one.
+pub fn read_named(dir: &str, name: &str) -> Result<Input, InputError> {
+    read_first(name, vec![Path::new(dir).join(name)], Key::load)
+}
+
+/// Reads the first of `tried` that exists. Encrypted files are decrypted with the key from
+/// `load_key` (normally [`Key::load`]), which is only called for them.
+fn read_first<F>(name: &str, tried: Vec<PathBuf>, load_key: F) -> Result<Input, InputError>
+where F: FnOnce() -> Result<Option<Key>, String> {
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => {
+            let text = fs::read_to_string(path).map_err(|err| InputError::Io { path: path.clone(), err })?;
+            let text = if crypt::is_encrypted(&text) { decrypted(path, &text, load_key)? } else { text };
+            Ok(Input { source: path.display().to_string(), text })
+        }
+        None => Err(InputError::NotFound { requested: name.to_string(), tried }),
+    }
+}
+
+fn decrypted<F>(path: &Path, text: &str, load_key: F) -> Result<String, InputError>
+where F: FnOnce() -> Result<Option<Key>, String> {
+    let fail = |message| InputError::Decrypt { path: path.to_path_buf(), message };
+    let key = load_key().map_err(fail)?.ok_or_else(|| InputError::NoKey { path: path.to_path_buf() })?;
+    crypt::decrypt(&key, text).map_err(fail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    #[test]
+    fn relative_to_dir() {
+        let dir = ScratchDir::new("input-relative");
+        fs::write(dir.join("example"), "1,2,3").unwrap();
+
+        let input = read_named(dir.path().to_str().unwrap(), "example").unwrap();
+        assert_eq!(input.text, "1,2,3");
+        assert_eq!(input.source, dir.join("example").display().to_string());
+    }
+
+    #[test]
+    fn encrypted() {
+        let dir = ScratchDir::new("input-encrypted");
+        let key = Key::generate();
+        fs::write(dir.join("input"), crypt::encrypt(&key, "L68\nR48\n")).unwrap();
+        let read = |load_key: &dyn Fn() -> Result<Option<Key>, String>| {
+            read_first("input", vec![dir.join("input")], load_key)
+        };
+
+        assert!(matches!(read(&|| Ok(None)), Err(InputError::NoKey { .. })));
+        assert!(matches!(read(&|| Err("bad key".to_string())), Err(InputError::Decrypt { .. })));
+        assert!(matches!(read(&|| Ok(Some(Key::generate()))), Err(InputError::Decrypt { .. })));
+        assert_eq!(read(&|| Ok(Some(key.clone()))).unwrap().text, "L68\nR48\n");
+        // plain files never need the key
+        fs::write(dir.join("input"), "L68\n").unwrap();
+        assert_eq!(read(&|| panic!("loaded a key for a plain file")).unwrap().text, "L68\n");
+    }
+
+    #[test]
+    fn missing() {
+        let dir = ScratchDir::new("input-missing");
+        match read_given(dir.path().to_str().unwrap(), "nope") {
+            Err(InputError::NotFound { tried, .. }) => {
+                assert_eq!(tried, vec![PathBuf::from("nope"), dir.join("nope")]);
+            }
+            other => panic!("Expected NotFound, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn named_ignores_working_dir() {
+        // tests run from the crate's directory, which has a Cargo.toml but the scratch dir doesn't
+        let dir = ScratchDir::new("input-named");
+        assert!(read_given(dir.path().to_str().unwrap(), "Cargo.toml").is_ok());
+        match read_named(dir.path().to_str().unwrap(), "Cargo.toml") {
+            Err(InputError::NotFound { tried, .. }) => assert_eq!(tried, vec![dir.join("Cargo.toml")]),
+            other => panic!("Expected NotFound, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::Answer;
+use std::fmt::Write;
+
+/// A single-line JSON object, built up one field at a time.
+#[derive(Debug, Default)]
+pub struct Object {
+    body: String,
+}
+
+impl Object {
+    pub fn new() -> Self {
+        Object::default()
+    }
+
+    fn key(&mut self, key: &str) {
+        if !self.body.is_empty() {
+            self.body.push(',');
+        }
+        self.body += &string(key);
+        self.body.push(':');
+    }
+
+    pub fn str(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        self.body += &string(value);
+        self
+    }
+
+    pub fn num<N: Into<i128>>(mut self, key: &str, value: N) -> Self {
+        self.key(key);
+        write!(self.body, "{}", value.into()).unwrap();
+        self
+    }
+
+    /// Integer answers are written as JSON numbers and text answers as strings.
+    pub fn answer(self, key: &str, answer: &Answer) -> Self {
+        match answer {
+            Answer::Int(n) => self.num(key, *n),
+            Answer::Text(s) => self.str(key, s),
+        }
+    }
+}
+
+impl std::fmt::Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{{}}}", self.body)
+    }
+}
+
+/// Quotes and escapes `s` as a JSON string.
+pub fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        assert_eq!(string("a\"b\\c\nd\u{1}é"), "\"a\\\"b\\\\c\\nd\\u0001é\"");
+    }
+
+    #[test]
+    fn object() {
+        let obj = Object::new()
+            .num("day", 5_u8)
+            .answer("answer", &Answer::Int(-3))
+            .answer("text", &Answer::Text("x".to_string()))
+            .str("input", "abc");
+        assert_eq!(obj.to_string(), r#"{"day":5,"answer":-3,"text":"x","input":"abc"}"#);
+        assert_eq!(Object::new().to_string(), "{}");
+    }
+}
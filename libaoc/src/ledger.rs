@@ -0,0 +1,241 @@
+use crate::{store, Answer};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Environment variable overriding where the ledger is kept.
+pub const LEDGER_ENV: &str = "AOC_LEDGER";
+
+/// Default ledger location: `answers.ledger` in the workspace root.
+pub const DEFAULT_LEDGER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.ledger");
+
+/// Stable fingerprint of an input's contents (64-bit FNV-1a, as hex), so answers are only compared
+/// against ones computed from the same input.
+pub fn fingerprint(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{hash:016x}")
+}
+
+/// What the site said about an answer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Accepted,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Status {
+    fn parse(s: &str) -> Option<Status> {
+        match s {
+            "accepted" => Some(Status::Accepted),
+            "wrong" => Some(Status::Wrong),
+            "too-high" => Some(Status::TooHigh),
+            "too-low" => Some(Status::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Accepted => write!(f, "accepted"),
+            Status::Wrong => write!(f, "wrong"),
+            Status::TooHigh => write!(f, "too-high"),
+            Status::TooLow => write!(f, "too-low"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub fingerprint: String,
+    pub status: Status,
+    pub answer: String,
+}
+
+/// How a freshly computed answer compares with the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Unknown,
+    Match,
+    Mismatch { expected: String },
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Unknown => write!(f, "unknown"),
+            Verdict::Match => write!(f, "match"),
+            Verdict::Mismatch { .. } => write!(f, "mismatch"),
+        }
+    }
+}
+
+/// Answers recorded for each (day, part, input fingerprint), one tab-separated entry per line.
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Opens the ledger named by `$AOC_LEDGER`, or the default one in the workspace root.
+    pub fn open() -> Result<Ledger, String> {
+        Ledger::open_at(store::env_path(LEDGER_ENV, DEFAULT_LEDGER))
+    }
+
+    /// Opens the ledger at `path`; a missing file is an empty ledger.
+    pub fn open_at<P: AsRef<Path>>(path: P) -> Result<Ledger, String> {
+        let path = path.as_ref().to_path_buf();
+        let entries = store::load_lines(&path, "ledger entry", parse_entry)?;
+        Ok(Ledger { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn accepted(&self, day: u8, part: u8, fingerprint: &str) -> Option<&Entry> {
+        self.entries.iter().find(|e| {
+            e.day == day && e.part == part && e.fingerprint == fingerprint && e.status == Status::Accepted
+        })
+    }
+
+    pub fn check(&self, day: u8, part: u8, fingerprint: &str, answer: &Answer) -> Verdict {
+        match self.accepted(day, part, fingerprint) {
+            None => Verdict::Unknown,
+            Some(entry) if entry.answer == answer.to_string() => Verdict::Match,
+            Some(entry) => Verdict::Mismatch { expected: entry.answer.clone() },
+        }
+    }
+
+    /// The rejected entry that rules `answer` out, if any: the same answer was already rejected, or
+    /// it's a number at or beyond one the site said was too high or too low.
+    pub fn known_wrong(&self, day: u8, part: u8, fingerprint: &str, answer: &Answer) -> Option<&Entry> {
+        let answer_text = answer.to_string();
+        self.entries
+            .iter()
+            .filter(|e| e.day == day && e.part == part && e.fingerprint == fingerprint)
+            .find(|e| {
+                let bound = e.answer.parse::<i128>().ok();
+                match (e.status, answer, bound) {
+                    (Status::Accepted, _, _) => false,
+                    _ if e.answer == answer_text => true,
+                    (Status::TooHigh, Answer::Int(n), Some(bound)) => *n >= bound,
+                    (Status::TooLow, Answer::Int(n), Some(bound)) => *n <= bound,
+                    _ => false,
+                }
+            })
+    }
+
+    /// Records that the site rejected `answer` with `status`, unless that's already known.
+    pub fn reject(&mut self, day: u8, part: u8, fingerprint: &str, status: Status, answer: &Answer) {
+        let answer = answer.to_string();
+        let known = self.entries.iter().any(|e| {
+            e.day == day && e.part == part && e.fingerprint == fingerprint && e.status == status && e.answer == answer
+        });
+        if !known {
+            self.entries.push(Entry { day, part, fingerprint: fingerprint.to_string(), status, answer });
+        }
+    }
+
+    /// Records `answer` as the accepted one, replacing any previously accepted answer.
+    pub fn accept(&mut self, day: u8, part: u8, fingerprint: &str, answer: &Answer) {
+        self.entries.retain(|e| {
+            !(e.day == day && e.part == part && e.fingerprint == fingerprint && e.status == Status::Accepted)
+        });
+        self.entries.push(Entry {
+            day,
+            part,
+            fingerprint: fingerprint.to_string(),
+            status: Status::Accepted,
+            answer: answer.to_string(),
+        });
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut text = String::from("# day\tpart\tinput fingerprint\tstatus\tanswer\n");
+        for e in &self.entries {
+            text += &format!("{}\t{}\t{}\t{}\t{}\n", e.day, e.part, e.fingerprint, e.status, e.answer);
+        }
+        fs::write(&self.path, text).map_err(|err| format!("Couldn't write {}: {err}", self.path.display()))
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut fields = line.splitn(5, '\t');
+    Some(Entry {
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        fingerprint: fields.next()?.to_string(),
+        status: Status::parse(fields.next()?)?,
+        answer: fields.next()?.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchDir;
+
+    #[test]
+    fn fingerprints() {
+        assert_eq!(fingerprint(""), "cbf29ce484222325");
+        assert_eq!(fingerprint("a"), "af63dc4c8601ec8c");
+        assert_ne!(fingerprint("L68\nL30"), fingerprint("L68\nL31"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let dir = ScratchDir::new("ledger");
+        let path = dir.join("answers.ledger");
+
+        let mut ledger = Ledger::open_at(&path).unwrap();
+        assert_eq!(ledger.check(5, 1, "abc", &Answer::Int(3)), Verdict::Unknown);
+        ledger.accept(5, 1, "abc", &Answer::Int(4));
+        ledger.accept(5, 1, "abc", &Answer::Int(3));
+        ledger.save().unwrap();
+
+        let ledger = Ledger::open_at(&path).unwrap();
+        assert_eq!(ledger.entries().len(), 1);
+        assert_eq!(ledger.check(5, 1, "abc", &Answer::Int(3)), Verdict::Match);
+        assert_eq!(ledger.check(5, 1, "def", &Answer::Int(3)), Verdict::Unknown);
+        assert_eq!(ledger.check(5, 1, "abc", &Answer::Int(7)), Verdict::Mismatch { expected: "3".to_string() });
+    }
+
+    #[test]
+    fn rejections() {
+        let dir = ScratchDir::new("ledger-rejections");
+        let path = dir.join("answers.ledger");
+
+        let mut ledger = Ledger::open_at(&path).unwrap();
+        ledger.reject(5, 1, "abc", Status::Wrong, &Answer::Int(50));
+        ledger.reject(5, 1, "abc", Status::TooHigh, &Answer::Int(100));
+        ledger.reject(5, 1, "abc", Status::TooLow, &Answer::Int(10));
+        ledger.reject(5, 1, "abc", Status::TooLow, &Answer::Int(10));
+        ledger.save().unwrap();
+
+        let ledger = Ledger::open_at(&path).unwrap();
+        assert_eq!(ledger.entries().len(), 3);
+        let status = |answer: i128| ledger.known_wrong(5, 1, "abc", &Answer::Int(answer)).map(|e| e.status);
+        assert_eq!(status(50), Some(Status::Wrong));
+        assert_eq!(status(120), Some(Status::TooHigh));
+        assert_eq!(status(10), Some(Status::TooLow));
+        assert_eq!(status(51), None);
+        assert_eq!(ledger.known_wrong(5, 2, "abc", &Answer::Int(50)), None);
+        // rejections are never mistaken for the right answer
+        assert_eq!(ledger.check(5, 1, "abc", &Answer::Int(50)), Verdict::Unknown);
+    }
+}
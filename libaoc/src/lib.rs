@@ -0,0 +1,311 @@
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod crypt;
+pub mod history;
+pub mod input;
+pub mod json;
+pub mod ledger;
+pub mod log;
+pub mod parse;
+pub mod rng;
+pub mod scratch;
+mod store;
+
+use ledger::{Ledger, Verdict};
+use parse::ParseError;
+use rng::Rng;
+
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+use std::{env, process};
+
+/// The answer to one part of a puzzle: either a number or a piece of text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// The separately measurable stages of solving a day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part1"),
+            Phase::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+/// One day's puzzle: parse the input once, then solve either part from the parsed form.
+pub trait Solution {
+    type Input;
+
+    /// Which day of the calendar this solves.
+    const DAY: u8;
+
+    /// The day's crate directory, used to find its `input` and `example` files.
+    /// Implementations set this to `env!("CARGO_MANIFEST_DIR")`.
+    const DIR: &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Checks `input` against the day's format rules, reporting every violation instead of stopping
+    /// at the first like `parse` does. Days without rules beyond what `parse` checks keep the default.
+    fn lint(_input: &str) -> Vec<ParseError> {
+        Vec::new()
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// The answers from one run of a day, along with how long each step took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub parts: Vec<SolvedPart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolvedPart {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// A generator of synthetic, valid inputs for a day, for stress testing and profiling.
+pub trait Generate: Solution {
+    /// Roughly the size of a real puzzle input.
+    const DEFAULT_SIZE: usize;
+
+    /// Produces an input of the given `size`; what `size` counts (lines, grid width, ...) is up to
+    /// each day. The same `rng` state must always produce the same input.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Validates `input` for `S` without solving it: every violation of the day's format rules, or if
+/// there are none, whatever `parse` still objects to.
+pub fn check<S: Solution>(input: &str) -> Vec<ParseError> {
+    let errors = S::lint(input);
+    if !errors.is_empty() {
+        return errors;
+    }
+    S::parse(input).err().into_iter().collect()
+}
+
+/// Generates an input for `S` from `seed`, using `S::DEFAULT_SIZE` if no `size` is given.
+pub fn generate<S: Generate>(seed: u64, size: Option<usize>) -> String {
+    S::generate(&mut Rng::new(seed), size.unwrap_or(S::DEFAULT_SIZE))
+}
+
+/// Parses `input` and solves each of the requested `parts` (1 or 2) in order.
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Solved, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed),
+                2 => S::part2(&parsed),
+                _ => panic!("There is no part {part}"),
+            };
+            SolvedPart { part, answer, elapsed: start.elapsed() }
+        })
+        .collect();
+    Ok(Solved { parse_time, parts })
+}
+
+/// How answers are written to stdout.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    /// `Part 1: 737` lines.
+    Text,
+    /// One JSON object per line, e.g.
+    /// `{"day":5,"part":1,"answer":737,"elapsed_ns":812,"parse_ns":4210,"input":"...","ledger":"match"}`.
+    Json,
+}
+
+/// Prints each answer, loudly flagging (on stderr) any that disagree with the ledger's accepted
+/// answer for the same input. Returns `false` if there were any mismatches.
+pub fn report(ledger: &Ledger, day: u8, fingerprint: &str, solved: &Solved, format: Format) -> bool {
+    let mut ok = true;
+    for SolvedPart { part, answer, elapsed } in &solved.parts {
+        let verdict = ledger.check(day, *part, fingerprint, answer);
+        match format {
+            Format::Text => println!("Part {part}: {answer}"),
+            Format::Json => {
+                let record = json::Object::new()
+                    .num("day", day)
+                    .num("part", *part)
+                    .answer("answer", answer)
+                    .num("elapsed_ns", elapsed.as_nanos() as i128)
+                    .num("parse_ns", solved.parse_time.as_nanos() as i128)
+                    .str("input", fingerprint)
+                    .str("ledger", &verdict.to_string());
+                println!("{record}");
+            }
+        }
+        if let Verdict::Mismatch { expected } = verdict {
+            eprintln!("!!! MISMATCH: day {day} part {part} gave {answer}, but the accepted answer is {expected} !!!");
+            ok = false;
+        }
+    }
+    ok
+}
+
+/// Entry point for the per-day binaries: solves both parts and prints them. Accepts `--json`, `-v`/`-vv`
+/// (debug/trace logging) and `--log FILTER`; the input is taken from the first other argument if given,
+/// otherwise resolved as described in [`input::read_input`].
+pub fn main<S: Solution>() {
+    let mut format = Format::Text;
+    let mut input_arg = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => format = Format::Json,
+            "-v" => log::init(log::Filter::level(log::Level::Debug)),
+            "-vv" => log::init(log::Filter::level(log::Level::Trace)),
+            "--log" => match args.next().map(|spec| log::Filter::parse(&spec)) {
+                Some(Ok(filter)) => log::init(filter),
+                Some(Err(err)) => {
+                    eprintln!("{err}");
+                    process::exit(2);
+                }
+                None => {
+                    eprintln!("Missing value for --log");
+                    process::exit(2);
+                }
+            },
+            _ => input_arg = Some(arg),
+        }
+    }
+
+    let input = match input::read_input(S::DIR, input_arg.as_deref()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+    let ledger = match Ledger::open() {
+        Ok(ledger) => ledger,
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(1);
+        }
+    };
+
+    let solved = match solve::<S>(&input.text, &[1, 2]) {
+        Ok(solved) => solved,
+        Err(err) => {
+            eprintln!("{}", err.with_file(input.source));
+            process::exit(1);
+        }
+    };
+    if !report(&ledger, S::DAY, &ledger::fingerprint(&input.text), &solved, format) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Input = Vec<u32>;
+        const DAY: u8 = 0;
+        const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input.split(',').map(|n| parse::parse_token(1, input, n)).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            format!("{} numbers", input.len()).into()
+        }
+    }
+
+    #[test]
+    fn solve_parts() {
+        let solved = solve::<Echo>("1,2,3", &[2, 1]).unwrap();
+        let answers: Vec<(u8, Answer)> = solved.parts.into_iter().map(|p| (p.part, p.answer)).collect();
+        assert_eq!(answers, vec![(2, Answer::Text("3 numbers".to_string())), (1, Answer::Int(6))]);
+    }
+
+    #[test]
+    fn solve_bad_input() {
+        let err = solve::<Echo>("1,x,3", &[1]).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 3, "x"));
+    }
+
+    #[test]
+    fn check_falls_back_to_parse() {
+        assert!(check::<Echo>("1,2,3").is_empty());
+        let errors = check::<Echo>("1,x,y");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, "x");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(-12_i32).to_string(), "-12");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
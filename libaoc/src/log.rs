@@ -0,0 +1,190 @@
+use std::fmt::{Arguments, Display, Formatter};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use std::env;
+
+/// Environment variable holding the log filter, e.g. `debug` or `d08=trace,warn`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn parse(s: &str) -> Option<Level> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Which levels are logged: a default, plus overrides for particular crates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Level,
+    targets: Vec<(String, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter { default: Level::Warn, targets: vec!() }
+    }
+}
+
+impl Filter {
+    /// Parses a comma-separated list of `level` and `crate=level` entries, e.g. `d08=trace,info`.
+    pub fn parse(spec: &str) -> Result<Filter, String> {
+        let mut filter = Filter::default();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let invalid = || format!("Invalid log level in {entry:?}, expected error, warn, info, debug or trace");
+            match entry.split_once('=') {
+                Some((target, level)) => {
+                    let level = Level::parse(level).ok_or_else(invalid)?;
+                    filter.targets.push((target.trim().to_string(), level));
+                }
+                None => filter.default = Level::parse(entry).ok_or_else(invalid)?,
+            }
+        }
+        Ok(filter)
+    }
+
+    /// A filter that logs everything up to `level`.
+    pub fn level(level: Level) -> Filter {
+        Filter { default: level, targets: vec!() }
+    }
+
+    pub fn allows(&self, level: Level, module: &str) -> bool {
+        let krate = module.split("::").next().unwrap_or(module);
+        let max = self
+            .targets
+            .iter()
+            .rev()
+            .find(|(target, _)| target == krate || target == module)
+            .map_or(self.default, |(_, level)| *level);
+        level <= max
+    }
+
+    fn max(&self) -> Level {
+        self.targets.iter().map(|(_, level)| *level).fold(self.default, Level::max)
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+// most verbose level any target allows, so disabled log lines cost a single load
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(u8::MAX);
+
+/// Installs `filter`. Only the first call (or first log line, which falls back to `$AOC_LOG`) counts.
+pub fn init(filter: Filter) {
+    let filter = FILTER.get_or_init(|| filter);
+    MAX_LEVEL.store(filter.max() as u8, Ordering::Relaxed);
+}
+
+/// Installs the filter from `$AOC_LOG`, or the default (warnings and errors) if it is unset or invalid.
+pub fn init_from_env() {
+    let filter = match env::var(LOG_ENV) {
+        Ok(spec) => Filter::parse(&spec).unwrap_or_else(|err| {
+            eprintln!("Ignoring ${LOG_ENV}: {err}");
+            Filter::default()
+        }),
+        Err(_) => Filter::default(),
+    };
+    init(filter);
+}
+
+pub fn enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    if FILTER.get().is_none() {
+        init_from_env();
+        return enabled(level, module);
+    }
+    FILTER.get().unwrap().allows(level, module)
+}
+
+/// Writes a log line to stderr; use the macros, which skip formatting when the level is disabled.
+pub fn write(level: Level, module: &str, args: Arguments) {
+    eprintln!("[{level:<5} {module}] {args}");
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters() {
+        let filter = Filter::parse("d08=trace, info").unwrap();
+        assert!(filter.allows(Level::Trace, "d08"));
+        assert!(filter.allows(Level::Trace, "d08::circuits"));
+        assert!(filter.allows(Level::Info, "d01"));
+        assert!(!filter.allows(Level::Debug, "d01"));
+        assert_eq!(filter.max(), Level::Trace);
+
+        let filter = Filter::default();
+        assert!(filter.allows(Level::Warn, "aoc"));
+        assert!(!filter.allows(Level::Info, "aoc"));
+    }
+
+    #[test]
+    fn bad_filters() {
+        assert!(Filter::parse("loud").is_err());
+        assert!(Filter::parse("d08=everything").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use lib2d::Grid;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A problem with an input, pointing at where in the input it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Where the input came from, if known. Parsers leave this empty and callers fill it in.
+    pub file: Option<String>,
+    /// 1-based line number; one past the last line for problems at the end of the input.
+    pub line: usize,
+    /// 1-based column, in characters.
+    pub column: usize,
+    /// The offending piece of the line (may be empty, e.g. for a missing value).
+    pub text: String,
+    /// The whole line the problem is on.
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error about `token`, which must be a slice of `line` (the `line_no`th line, 1-based).
+    pub fn at(line_no: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = token.as_ptr() as usize;
+        let start = line.as_ptr() as usize;
+        let column = if offset >= start && offset + token.len() <= start + line.len() {
+            line[..offset - start].chars().count() + 1
+        } else {
+            1
+        };
+        ParseError {
+            file: None,
+            line: line_no,
+            column,
+            text: token.to_string(),
+            source_line: line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about the `column_idx`th character (0-based) of `line`.
+    pub fn at_column(line_no: usize, line: &str, column_idx: usize, message: impl Into<String>) -> Self {
+        let text = line.chars().nth(column_idx).map(String::from).unwrap_or_default();
+        ParseError {
+            file: None,
+            line: line_no,
+            column: column_idx + 1,
+            text,
+            source_line: line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about the whole `line_no`th line.
+    pub fn line(line_no: usize, line: &str, message: impl Into<String>) -> Self {
+        ParseError::at(line_no, line, line, message)
+    }
+
+    /// An error about something missing from the end of `input`.
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: input.lines().count() + 1,
+            column: 1,
+            text: String::new(),
+            source_line: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        writeln!(f, "{file}:{}:{}: {}", self.line, self.column, self.message)?;
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        let underline = "^".repeat(self.text.chars().count().max(1));
+        write!(f, "{gutter} | {}{underline}", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token` (a slice of `line`), reporting where it was if it isn't a valid `T`.
+pub fn parse_token<T>(line_no: usize, line: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|err| ParseError::at(line_no, line, token, format!("couldn't parse {token:?}: {err}")))
+}
+
+/// Checks that `input` is a rectangular grid made only of `symbols`, reporting every line of the
+/// wrong width and every unexpected character.
+pub fn check_grid(input: &str, symbols: &str) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    let Some(width) = input.lines().next().map(|line| line.chars().count()) else {
+        errors.push(ParseError::end_of_input(input, "expected a grid"));
+        return errors;
+    };
+    let mut expected: Vec<String> = symbols.chars().map(|c| format!("{c:?}")).collect();
+    let last = expected.pop().unwrap_or_default();
+    let expected = if expected.is_empty() { last } else { format!("{} or {last}", expected.join(", ")) };
+
+    for (idx, line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        let length = line.chars().count();
+        if length != width {
+            errors.push(ParseError::line(line_no, line, format!("expected {width} columns like the first row, got {length}")));
+        }
+        for (x, char) in line.chars().enumerate() {
+            if !symbols.contains(char) {
+                errors.push(ParseError::at_column(line_no, line, x, format!("expected {expected}")));
+            }
+        }
+    }
+    errors
+}
+
+/// Parses a grid made only of `symbols`, turning each into a cell with `cell`. Problems are reported
+/// the way [`check_grid`] reports them, stopping at the first.
+pub fn parse_grid<T, F>(input: &str, symbols: &str, mut cell: F) -> Result<Grid<T>, ParseError>
+where F: FnMut(char) -> T {
+    if let Some(err) = check_grid(input, symbols).into_iter().next() {
+        return Err(err);
+    }
+    Ok(Grid::parse(input, |char| Some(cell(char))).expect("check_grid accepts only rectangular grids of symbols"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_from_token() {
+        let line = "12,x4,7";
+        let token = line.split(',').nth(1).unwrap();
+        let err = ParseError::at(3, line, token, "bad number");
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "x4"));
+    }
+
+    #[test]
+    fn parse_tokens() {
+        let line = "L68 Rx";
+        assert_eq!(parse_token::<u32>(1, line, &line[1..3]), Ok(68));
+        let err = parse_token::<u32>(1, line, &line[5..]).unwrap_err();
+        assert_eq!(err.column, 6);
+    }
+
+    #[test]
+    fn grid() {
+        assert!(check_grid("..#\n#..\n", ".#").is_empty());
+        let errors = check_grid("..#\n#x\n.y.\n", ".#");
+        let found: Vec<_> = errors.iter().map(|e| (e.line, e.column, e.text.as_str())).collect();
+        assert_eq!(found, vec![(2, 1, "#x"), (2, 2, "x"), (3, 2, "y")]);
+        assert_eq!(errors[2].message, "expected '.' or '#'");
+        assert_eq!(check_grid("x", ".S^")[0].message, "expected '.', 'S' or '^'");
+        assert_eq!(check_grid("", ".").len(), 1);
+
+        let grid = parse_grid("..#\n#..\n", ".#", |c| c == '#').unwrap();
+        assert_eq!(grid.row(0), [false, false, true]);
+        assert_eq!(parse_grid("..#\n#x\n", ".#", |c| c == '#').unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn render() {
+        let line = "R48 X5";
+        let err = ParseError::at(12, line, &line[4..], "expected L or R").with_file("d01/input");
+        assert_eq!(
+            err.to_string(),
+            "d01/input:12:5: expected L or R\n   |\n12 | R48 X5\n   |     ^^"
+        );
+    }
+}
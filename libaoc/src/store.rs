@@ -0,0 +1,28 @@
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+/// The path in the environment variable `var`, or `default` if it isn't set.
+pub(crate) fn env_path(var: &str, default: &str) -> PathBuf {
+    env::var_os(var).map_or_else(|| PathBuf::from(default), PathBuf::from)
+}
+
+/// Parses each line of the file at `path` with `parse`, skipping blank lines and `#` comments. A
+/// missing file has no lines; `what` names a line in the error for one that doesn't parse.
+pub(crate) fn load_lines<T, F>(path: &Path, what: &str, parse: F) -> Result<Vec<T>, String>
+where F: Fn(&str) -> Option<T> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(format!("Couldn't read {}: {err}", path.display())),
+    };
+
+    let mut items = vec!();
+    for (idx, line) in text.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let item = parse(line).ok_or_else(|| format!("{}:{}: malformed {what} {line:?}", path.display(), idx + 1))?;
+        items.push(item);
+    }
+    Ok(items)
+}
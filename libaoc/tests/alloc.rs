@@ -0,0 +1,56 @@
+use libaoc::alloc::{during, installed, measure, Counting};
+use libaoc::parse::ParseError;
+use libaoc::{Answer, Phase, Solution};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+struct Words;
+
+impl Solution for Words {
+    type Input = Vec<String>;
+    const DAY: u8 = 0;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.split(' ').map(|word| word.to_string()).collect())
+    }
+
+    fn part1(words: &Self::Input) -> Answer {
+        words.len().into()
+    }
+
+    fn part2(words: &Self::Input) -> Answer {
+        words.concat().into()
+    }
+}
+
+// the counters are global, so everything is checked in one test to keep other tests' threads out
+#[test]
+fn counts() {
+    assert!(installed());
+
+    let (_, stats) = during(|| {
+        let first = black_box(vec![0u8; 1000]);
+        drop(first);
+        black_box(vec![0u8; 600]);
+    });
+    assert_eq!((stats.allocations, stats.bytes, stats.peak_bytes), (2, 1600, 1000));
+
+    let (_, stats) = during(|| {
+        let mut grown = black_box(Vec::<u8>::with_capacity(10));
+        grown.reserve_exact(90);
+        grown
+    });
+    assert_eq!((stats.allocations, stats.bytes, stats.peak_bytes), (2, 100, 90));
+
+    let phases = measure::<Words>("ab cd ef").unwrap();
+    let phases: Vec<_> = phases.into_iter().map(|(phase, stats)| (phase, stats.allocations)).collect();
+    assert_eq!(phases[0].0, Phase::Parse);
+    // three words, plus at least one for the Vec holding them
+    assert!(phases[0].1 >= 4, "{phases:?}");
+    assert_eq!(phases[1], (Phase::Part1, 0));
+    // the joined string, plus the Answer's copy of it
+    assert!(phases[2].1 >= 1, "{phases:?}");
+}